@@ -0,0 +1,82 @@
+use syn::{DeriveInput, Field, Lit, LitStr, Meta, NestedMeta};
+
+/// Attribute `#[envuse(path = "...")]` of the struct
+pub struct StructAttributes {
+    pub path: String,
+    pub path_lit: LitStr,
+}
+
+/// Attribute `#[envuse(name = "...")]` of a field
+pub struct FieldAttributes {
+    pub name: Option<String>,
+}
+
+fn envuse_name_values(attrs: &[syn::Attribute]) -> syn::Result<Vec<(String, LitStr)>> {
+    let mut name_values = vec![];
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("envuse")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => do yeet syn::Error::new_spanned(meta, "Expected #[envuse(key = \"value\")]"),
+        };
+
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(name_value)) => {
+                    let key = match name_value.path.get_ident() {
+                        Some(ident) => ident.to_string(),
+                        None => do yeet syn::Error::new_spanned(name_value.path, "Unknown key"),
+                    };
+                    match name_value.lit {
+                        Lit::Str(lit) => name_values.push((key, lit)),
+                        lit => do yeet syn::Error::new_spanned(lit, "Expected a string"),
+                    }
+                }
+                nested => {
+                    do yeet syn::Error::new_spanned(nested, "Expected #[envuse(key = \"value\")]")
+                }
+            }
+        }
+    }
+
+    Ok(name_values)
+}
+
+impl StructAttributes {
+    pub fn from_attributes(input: &DeriveInput) -> syn::Result<Self> {
+        let mut path_lit: Option<LitStr> = None;
+
+        for (key, lit) in envuse_name_values(&input.attrs)? {
+            match key.as_str() {
+                "path" => path_lit = Some(lit),
+                _ => do yeet syn::Error::new_spanned(lit, format!("Unknown key {}", key)),
+            }
+        }
+
+        match path_lit {
+            Some(path_lit) => Ok(Self {
+                path: path_lit.value(),
+                path_lit,
+            }),
+            None => Err(syn::Error::new_spanned(
+                &input.ident,
+                "Expected the attribute #[envuse(path = \"...\")]",
+            )),
+        }
+    }
+}
+
+impl FieldAttributes {
+    pub fn from_field(field: &Field) -> syn::Result<Self> {
+        let mut name: Option<String> = None;
+
+        for (key, lit) in envuse_name_values(&field.attrs)? {
+            match key.as_str() {
+                "name" => name = Some(lit.value()),
+                _ => do yeet syn::Error::new_spanned(lit, format!("Unknown key {}", key)),
+            }
+        }
+
+        Ok(Self { name })
+    }
+}
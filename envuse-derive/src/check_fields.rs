@@ -0,0 +1,244 @@
+use envuse_parser::envuse::evaluate_options::EvaluateOptions;
+use envuse_parser::parser::ast::Variable;
+use envuse_parser::transformers::parser::Parser;
+use envuse_parser::transformers::type_options::TypeOptions;
+use envuse_parser::transformers::value_types::ValueType;
+use syn::{Field, GenericArgument, PathArguments, Type};
+
+const INTEGER_TYPES: [(&str, i128, i128); 12] = [
+    ("u8", u8::MIN as i128, u8::MAX as i128),
+    ("u16", u16::MIN as i128, u16::MAX as i128),
+    ("u32", u32::MIN as i128, u32::MAX as i128),
+    ("u64", u64::MIN as i128, u64::MAX as i128),
+    ("u128", u128::MIN as i128, i128::MAX),
+    ("usize", usize::MIN as i128, usize::MAX as i128),
+    ("i8", i8::MIN as i128, i8::MAX as i128),
+    ("i16", i16::MIN as i128, i16::MAX as i128),
+    ("i32", i32::MIN as i128, i32::MAX as i128),
+    ("i64", i64::MIN as i128, i64::MAX as i128),
+    ("i128", i128::MIN, i128::MAX),
+    ("isize", isize::MIN as i128, isize::MAX as i128),
+];
+
+fn type_ident(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
+
+fn generic_inner<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let segment = match ty {
+        Type::Path(type_path) => type_path.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident != wrapper {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+            Some(GenericArgument::Type(inner)) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn expected_types(transform_type: &str) -> Option<Vec<&'static str>> {
+    match transform_type {
+        "str" | "string" | "secret" => Some(vec!["String"]),
+        "url" => Some(vec!["String", "Url"]),
+        "int" | "integer" | "number" | "uint" | "bytesize" => {
+            Some(INTEGER_TYPES.iter().map(|(name, ..)| *name).collect())
+        }
+        "float" => Some(vec!["f32", "f64"]),
+        "bool" | "boolean" => Some(vec!["bool"]),
+        "duration" => Some(vec!["Duration"]),
+        "port" => Some(vec!["u16", "u32", "u64", "usize", "i32", "i64"]),
+        "ipaddr" => Some(vec!["IpAddr", "Ipv4Addr", "Ipv6Addr", "String"]),
+        "socketaddr" => Some(vec!["SocketAddr", "SocketAddrV4", "SocketAddrV6", "String"]),
+        "host" => Some(vec!["String"]),
+        _ => None,
+    }
+}
+
+/// Check the field is compatible with the variable. Returns the type of the
+/// variable if is a custom type.
+pub fn check_field(field: &Field, variable: &Variable) -> syn::Result<Option<String>> {
+    let variable_type = variable
+        .variable_type
+        .clone()
+        .unwrap_or_else(|| String::from("String"));
+    let transform_type = variable_type.to_lowercase();
+
+    // The value can be null without a value in the environment, in the base
+    // declaration or in any of the profiles of the variable
+    let profiles = variable
+        .profiles
+        .iter()
+        .map(|profile| Some(profile.name.to_string()));
+    let can_be_null = [None].into_iter().chain(profiles).any(|profile| {
+        let mut options = EvaluateOptions::new();
+        options.profile = profile;
+
+        Parser::is_nullable(&options, variable)
+            && Parser::default_value(&options, variable).is_none()
+    });
+    let (ty, is_option) = match generic_inner(&field.ty, "Option") {
+        Some(inner) => (inner, true),
+        None => (&field.ty, false),
+    };
+
+    if can_be_null && !is_option {
+        do yeet syn::Error::new_spanned(
+            &field.ty,
+            format!(
+                "Variable {} is nullable, expected an Option",
+                variable.env_name()
+            ),
+        )
+    }
+
+    if !can_be_null && is_option {
+        do yeet syn::Error::new_spanned(
+            &field.ty,
+            format!(
+                "Variable {} cannot be null, expected a type without Option",
+                variable.env_name()
+            ),
+        )
+    }
+
+    let options = EvaluateOptions::new();
+
+    if transform_type == "list" {
+        return check_list(ty, variable, &options);
+    }
+
+    let transformer = match options.transformers.get(&transform_type) {
+        Some(transformer) => transformer,
+        None => return Ok(Some(variable_type)),
+    };
+
+    let ident = type_ident(ty).unwrap_or_default();
+
+    if let Some(expected_types) = expected_types(&transform_type) {
+        if !expected_types.contains(&ident.as_str()) {
+            do yeet syn::Error::new_spanned(
+                ty,
+                format!(
+                    "Variable {} is {}, expected one of {}",
+                    variable.env_name(),
+                    variable_type,
+                    expected_types.join(", ")
+                ),
+            )
+        }
+    }
+
+    // The default values of the profiles are checked too. The references
+    // `${NAME}` are only known when the values are read.
+    let default_values = [variable.default_value.as_ref()]
+        .into_iter()
+        .chain(
+            variable
+                .profiles
+                .iter()
+                .map(|profile| profile.default_value.as_ref()),
+        )
+        .filter_map(|expression| expression.as_ref().and_then(|e| e.as_default_value()))
+        .filter(|default_value| !default_value.value.contains("${"));
+
+    let type_options = TypeOptions::from_variable(variable);
+
+    for default_value in default_values {
+        let number = match transformer.parse(
+            transform_type.to_string(),
+            default_value.value.to_string(),
+            &type_options,
+        ) {
+            Ok(ValueType::Number(number)) => number as i128,
+            Ok(ValueType::UInt(number)) => number as i128,
+            Ok(_) => continue,
+            Err(error) => {
+                do yeet syn::Error::new_spanned(
+                    ty,
+                    format!(
+                        "Default value of {} is not valid: {}",
+                        variable.env_name(),
+                        error
+                    ),
+                )
+            }
+        };
+
+        let in_range = INTEGER_TYPES
+            .iter()
+            .find(|(name, ..)| *name == ident)
+            .map_or(true, |(_, min, max)| (*min..=*max).contains(&number));
+
+        if !in_range {
+            do yeet syn::Error::new_spanned(
+                ty,
+                format!(
+                    "Default value {} of {} is out of range for {}",
+                    number,
+                    variable.env_name(),
+                    ident
+                ),
+            )
+        }
+    }
+
+    Ok(None)
+}
+
+/// Check the field of a `List` variable is a `Vec` of the element type.
+/// Returns the element type if is a custom type.
+fn check_list(
+    ty: &Type,
+    variable: &Variable,
+    options: &EvaluateOptions,
+) -> syn::Result<Option<String>> {
+    let inner = match generic_inner(ty, "Vec") {
+        Some(inner) => inner,
+        None => {
+            do yeet syn::Error::new_spanned(
+                ty,
+                format!("Variable {} is List, expected a Vec", variable.env_name()),
+            )
+        }
+    };
+
+    let type_options = TypeOptions::from_variable(variable);
+    let element_type = Parser::list_element_type(options, &type_options);
+    let transform_type = element_type.to_lowercase();
+
+    if !options.transformers.contains_key(&transform_type) {
+        return Ok(Some(element_type));
+    }
+
+    if let Some(expected_types) = expected_types(&transform_type) {
+        let ident = type_ident(inner).unwrap_or_default();
+
+        if !expected_types.contains(&ident.as_str()) {
+            do yeet syn::Error::new_spanned(
+                inner,
+                format!(
+                    "Elements of {} are {}, expected one of {}",
+                    variable.env_name(),
+                    element_type,
+                    expected_types.join(", ")
+                ),
+            )
+        }
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,166 @@
+use crate::attributes::{FieldAttributes, StructAttributes};
+use crate::check_fields::check_field;
+use envuse_parser::create_program_with_reader;
+use envuse_parser::parser::ast::Variable;
+use quote::quote;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use syn::{Data, DeriveInput, Fields};
+
+/// Check the struct against the envuse file of `#[envuse(path = "...")]` and
+/// generate `from_env` and `from_envs`
+pub fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let struct_attributes = StructAttributes::from_attributes(input)?;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                do yeet syn::Error::new_spanned(
+                    &input.ident,
+                    "Envuse can only be derived for structs with named fields",
+                )
+            }
+        },
+        _ => {
+            do yeet syn::Error::new_spanned(&input.ident, "Envuse can only be derived for structs")
+        }
+    };
+
+    let location = struct_attributes.path.as_str();
+    let manifest_path = |path: &str| {
+        PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default())
+            .join(path)
+            .to_string_lossy()
+            .to_string()
+    };
+    let full_path = manifest_path(location);
+
+    let source = match std::fs::read_to_string(&full_path) {
+        Ok(source) => source,
+        Err(error) => {
+            do yeet syn::Error::new_spanned(
+                &struct_attributes.path_lit,
+                format!("Cannot read {}: {}", full_path, error),
+            )
+        }
+    };
+
+    let program = match create_program_with_reader(source.as_str(), Some(location), |path| {
+        std::fs::read_to_string(manifest_path(path))
+    }) {
+        Ok(program) => program,
+        Err(error) => do yeet syn::Error::new_spanned(&struct_attributes.path_lit, error),
+    };
+
+    let mut variables: BTreeMap<String, &Variable> = program
+        .ast
+        .as_document()
+        .map(|document| {
+            document
+                .variables()
+                .into_iter()
+                .map(|variable| (variable.env_name(), variable))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // The included files are embedded as well, the program is read again at runtime
+    let file_locations: Vec<&str> = program
+        .files
+        .iter()
+        .map(|file| file.location.as_str())
+        .collect();
+    let file_full_paths: Vec<String> = file_locations
+        .iter()
+        .map(|location| manifest_path(location))
+        .collect();
+
+    let mut errors: Vec<syn::Error> = vec![];
+    let mut custom_types: Vec<String> = vec![];
+    let mut field_values = vec![];
+
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let name = FieldAttributes::from_field(field)?
+            .name
+            .unwrap_or_else(|| ident.to_string().to_uppercase());
+
+        let variable = match variables.remove(&name) {
+            Some(variable) => variable,
+            None => {
+                errors.push(syn::Error::new_spanned(
+                    ident,
+                    format!("Variable {} is not declared in {}", name, location),
+                ));
+                continue;
+            }
+        };
+
+        match check_field(field, variable) {
+            Ok(Some(custom_type)) if !custom_types.contains(&custom_type) => {
+                custom_types.push(custom_type)
+            }
+            Ok(_) => {}
+            Err(error) => errors.push(error),
+        }
+
+        let key = variable.key_path();
+
+        field_values.push(quote! {
+            #ident: evaluate.deserialize_variable(&program, &mut values, &[#(#key),*])?
+        });
+    }
+
+    for name in variables.keys() {
+        errors.push(syn::Error::new_spanned(
+            &input.ident,
+            format!("Variable {} declared in {} has no field", name, location),
+        ));
+    }
+
+    if let Some(error) = errors.into_iter().reduce(|mut error, next| {
+        error.combine(next);
+        error
+    }) {
+        do yeet error
+    }
+
+    let ident = &input.ident;
+    let custom_types_len = custom_types.len();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Read the values from the environment of the process
+            pub fn from_env() -> Result<Self, Box<dyn std::error::Error>> {
+                Self::from_envs(::envuse_parser::envuse::to_envs::ProcessEnvs)
+            }
+
+            pub fn from_envs<T: ::envuse_parser::envuse::to_envs::ToEnvs>(
+                values: T,
+            ) -> Result<Self, Box<dyn std::error::Error>> {
+                let program = ::envuse_parser::create_program_with_reader(
+                    include_str!(#full_path),
+                    Some(#location),
+                    |path| match path {
+                        #(#file_locations => Ok(include_str!(#file_full_paths).to_string()),)*
+                        _ => Err(std::io::Error::from(std::io::ErrorKind::NotFound)),
+                    },
+                )?;
+                let custom_types: [&str; #custom_types_len] = [#(#custom_types),*];
+                let mut options = ::envuse_parser::envuse::evaluate_options::EvaluateOptions::new();
+                options.set_envs(values);
+                options.insert_custom_transformers(custom_types);
+
+                // The errors of the fields are shown on the span of their variables
+                let evaluate = ::envuse_parser::envuse::evaluate::Evaluate::new(options);
+                let mut values = evaluate.evaluate(&program)?;
+
+                Ok(Self {
+                    #(#field_values),*
+                })
+            }
+        }
+    })
+}
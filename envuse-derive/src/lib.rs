@@ -0,0 +1,38 @@
+//! Derive macro to keep a struct in sync with an envuse file.
+//!
+//! ```ignore
+//! #[derive(Envuse)]
+//! #[envuse(path = ".envuse")]
+//! struct Config {
+//!     port: u16,
+//!     #[envuse(name = "DATABASE_URL")]
+//!     database: String,
+//!     token: Option<String>,
+//! }
+//!
+//! let config = Config::from_env()?;
+//! ```
+//!
+//! The file is read at compile time and the compilation fails if a variable
+//! has no field (or the opposite), or the type of a field does not match the
+//! type, nullable and default value of its variable.
+
+#![feature(yeet_expr)]
+
+mod attributes;
+mod check_fields;
+mod expand;
+
+use expand::expand;
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+#[proc_macro_derive(Envuse, attributes(envuse))]
+pub fn derive_envuse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
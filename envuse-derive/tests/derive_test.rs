@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod derive_test {
+    use envuse_derive::Envuse;
+    use insta::{assert_debug_snapshot, assert_snapshot};
+
+    #[allow(dead_code)]
+    #[derive(Debug, Envuse)]
+    #[envuse(path = "tests/fixtures/app.envuse")]
+    struct AppConfig {
+        host: String,
+        port: u16,
+        debug: bool,
+        #[envuse(name = "DATABASE_URL")]
+        database: String,
+        database_pool_size: u32,
+        token: Option<String>,
+        region: String,
+        allowed_hosts: Vec<String>,
+        cache_enabled: bool,
+    }
+
+    #[test]
+    fn should_read_the_struct_from_values() {
+        let config = AppConfig::from_envs([
+            ("DATABASE_URL", "postgres://localhost/app"),
+            ("DEBUG", "true"),
+        ])
+        .unwrap();
+
+        assert_debug_snapshot!(config);
+    }
+
+    #[test]
+    fn should_catch_the_errors_of_the_values() {
+        let error = AppConfig::from_envs([("PORT", "abc")]).unwrap_err();
+
+        assert_snapshot!(error.to_string());
+    }
+
+    #[test]
+    fn should_catch_the_errors_of_the_fields() {
+        let error = AppConfig::from_envs([
+            ("DATABASE_URL", "postgres://localhost/app"),
+            ("PORT", "70000"),
+        ])
+        .unwrap_err();
+
+        assert_snapshot!(error.to_string());
+    }
+}
@@ -0,0 +1,117 @@
+#![feature(yeet_expr)]
+
+// The functions of a proc-macro crate cannot be exported, so the modules of
+// the derive are compiled again by this test
+#[path = "../src/attributes.rs"]
+mod attributes;
+#[path = "../src/check_fields.rs"]
+mod check_fields;
+#[path = "../src/expand.rs"]
+mod expand;
+
+#[cfg(test)]
+mod expand_test {
+    use super::check_fields::check_field;
+    use super::expand::expand;
+    use envuse_parser::create_program;
+    use insta::{assert_debug_snapshot, assert_snapshot};
+    use syn::{parse_quote, Data, DeriveInput, Fields};
+
+    /// Check the field with the variable declared in the source
+    fn check(source: &str, field: &str) -> Result<Option<String>, String> {
+        let program = create_program(source, None).unwrap();
+        let document = program.ast.as_document().unwrap();
+        let input: DeriveInput = syn::parse_str(&format!("struct A {{ {} }}", field)).unwrap();
+        let field = match input.data {
+            Data::Struct(data) => match data.fields {
+                Fields::Named(fields) => fields.named.into_iter().next().unwrap(),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+
+        check_field(&field, document.variables()[0]).map_err(|error| error.to_string())
+    }
+
+    /// Show the paths of the files relative to the derive crate
+    fn relative_paths(value: String) -> String {
+        value.replace(env!("CARGO_MANIFEST_DIR"), "[CARGO_MANIFEST_DIR]")
+    }
+
+    #[test]
+    fn should_check_the_fields() {
+        let results: Vec<_> = [
+            ("PORT: Number = 8080", "port: u16"),
+            ("REGION: AwsRegion", "region: String"),
+            ("PORT: Number", "port: String"),
+            ("TOKEN: String?", "token: String"),
+            ("HOST: String", "host: Option<String>"),
+            ("PORT: Number = 70000", "port: u16"),
+            ("PORT: Number = 80 @dev = 70000", "port: u16"),
+            ("HOSTS: List<String>", "hosts: String"),
+            ("HOSTS: List<Number>", "hosts: Vec<String>"),
+            ("TOKEN: String? @prod = \"x\"", "token: String"),
+            ("TOKEN: String? @prod required", "token: Option<String>"),
+        ]
+        .iter()
+        .map(|(source, field)| (*source, *field, check(source, field)))
+        .collect();
+
+        assert_debug_snapshot!(results);
+    }
+
+    #[test]
+    fn should_expand_a_struct_in_sync_with_the_file() {
+        let tokens = expand(&parse_quote! {
+            #[envuse(path = "tests/fixtures/app.envuse")]
+            struct AppConfig {
+                host: String,
+                port: u16,
+                debug: bool,
+                #[envuse(name = "DATABASE_URL")]
+                database: String,
+                database_pool_size: u32,
+                token: Option<String>,
+                region: String,
+                allowed_hosts: Vec<String>,
+                cache_enabled: bool,
+            }
+        })
+        .unwrap();
+
+        assert_snapshot!(relative_paths(tokens.to_string()));
+    }
+
+    #[test]
+    fn should_catch_the_errors_of_the_struct() {
+        let errors: Vec<Vec<String>> = [
+            parse_quote! {
+                #[envuse(path = "tests/fixtures/app.envuse")]
+                struct AppConfig {
+                    host: String,
+                    port: u16,
+                    unknown: String,
+                }
+            },
+            parse_quote! {
+                #[envuse(path = "tests/fixtures/missing.envuse")]
+                struct AppConfig {}
+            },
+            parse_quote! {
+                #[envuse(path = "tests/fixtures/app.envuse")]
+                enum AppConfig {}
+            },
+        ]
+        .iter()
+        .map(|input| {
+            expand(input)
+                .unwrap_err()
+                .into_iter()
+                .map(|error| relative_paths(error.to_string()))
+                .collect()
+        })
+        .collect();
+
+        assert_debug_snapshot!(errors);
+    }
+}
@@ -1,21 +1,311 @@
-use super::super::parser::ast::AST;
+use super::super::errors::parse_errors::ParseErrors;
+use super::super::errors::parser_error::ParseError;
+use super::super::parser::ast::{Expression, AST};
 use super::super::parser::tokenizer::Tokenizer;
-use super::display_program_error::display_program_error;
+use super::super::transformers::parser::Parser;
+use super::super::utils::did_you_mean::did_you_mean;
+use super::display_program_error::{display_program_error, display_program_error_with_files};
+use super::evaluate::{redact, sensitive_spans};
 use super::program::Program;
+use super::source_file::{position, SourceFile};
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::path::{Component, Path, PathBuf};
 
 pub fn create_program<T: ToString>(
     source: T,
     location: Option<T>,
 ) -> Result<Program, Box<dyn std::error::Error>> {
+    create_program_with_reader(source, location, |path| std::fs::read_to_string(path))
+}
+
+/// Create the program reading the files included with `@include "..."` by
+/// the `reader`. The reader receives the path of the file resolved from the
+/// location of the file that includes it.
+pub fn create_program_with_reader<T, R>(
+    source: T,
+    location: Option<T>,
+    reader: R,
+) -> Result<Program, Box<dyn std::error::Error>>
+where
+    T: ToString,
+    R: Fn(&str) -> std::io::Result<String>,
+{
     let location_val = location.map(|t| t.to_string());
+    let mut files = vec![];
+    let mut spans = vec![];
 
     let result_program: Result<Program, Box<dyn std::error::Error>> = try {
-        Program {
+        let mut ast = AST::parse(Tokenizer::parse(source.to_string())?)?;
+
+        let mut includes = Includes {
+            reader: &reader,
+            stack: vec![normalize(location_val.as_deref().unwrap_or_default())],
+            files: &mut files,
+            offset: source.to_string().chars().count() + 1,
+        };
+        if let Expression::Document(document) = &mut ast {
+            includes.resolve(&mut document.elements, location_val.as_deref(), &mut vec![])?;
+        }
+
+        let program = Program {
             location: location_val.clone(),
             source: source.to_string(),
-            ast: AST::parse(Tokenizer::parse(source.to_string())?)?,
+            ast,
+            files: files.clone(),
+        };
+
+        if let Some(document) = program.ast.as_document() {
+            spans = sensitive_spans(document);
+        }
+
+        check_duplicates(&program)?;
+        check_constraints(&program)?;
+
+        program
+    };
+
+    display_program_error_with_files(result_program, source, location_val, &files)
+        .map_err(|error| redact(error, &spans))
+}
+
+/// Normalize a path without reading the disk, e.g. `a/./b/../c` to `a/c`
+fn normalize(path: &str) -> String {
+    let mut normalized = PathBuf::new();
+
+    for component in Path::new(path).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+
+    normalized.to_string_lossy().to_string()
+}
+
+/// State to load the included files. The spans of each file are moved after
+/// the previous file, so the spans of the program never overlap.
+struct Includes<'a, R> {
+    reader: &'a R,
+    stack: Vec<String>,
+    files: &'a mut Vec<SourceFile>,
+    offset: usize,
+}
+
+impl<'a, R> Includes<'a, R>
+where
+    R: Fn(&str) -> std::io::Result<String>,
+{
+    /// Read the files included by the elements, `sections` are the names of
+    /// the sections that contain the elements
+    fn resolve(
+        &mut self,
+        elements: &mut [Expression],
+        location: Option<&str>,
+        sections: &mut Vec<String>,
+    ) -> Result<(), Box<dyn Error>> {
+        for element in elements.iter_mut() {
+            let include = match element {
+                Expression::Include(include) => include,
+                Expression::Section(section) => {
+                    sections.push(section.name.to_string());
+                    self.resolve(&mut section.elements, location, sections)?;
+                    sections.pop();
+                    continue;
+                }
+                _ => continue,
+            };
+
+            let base = location
+                .and_then(|location| Path::new(location).parent())
+                .unwrap_or_else(|| Path::new(""));
+            let path = normalize(&base.join(&include.path).to_string_lossy());
+
+            if self.stack.contains(&path) {
+                do yeet ParseError::new(
+                    format!(
+                        "The include has a cycle {} -> {}",
+                        self.stack.join(" -> "),
+                        path
+                    ),
+                    include.path_span,
+                )
+            }
+
+            let source = match (self.reader)(&path) {
+                Ok(source) => source,
+                Err(error) => {
+                    do yeet ParseError::new(
+                        format!("Cannot read the file {}: {}", path, error),
+                        include.path_span,
+                    )
+                }
+            };
+
+            // The syntax errors are shown with the source of the included file
+            let parsed: Result<Expression, Box<dyn Error>> =
+                try { AST::parse(Tokenizer::parse(&source)?)? };
+            let mut ast = display_program_error(parsed, &source, Some(path.clone()))?;
+            for section in sections.iter().rev() {
+                ast.nest_in_section(section);
+            }
+
+            ast.shift_spans(self.offset);
+            self.files.push(SourceFile {
+                location: path.clone(),
+                source,
+                offset: self.offset,
+            });
+            self.offset += self.files.last().map(|file| file.len() + 1).unwrap_or(0);
+
+            if let Expression::Document(document) = ast {
+                include.elements = document.elements;
+            }
+
+            self.stack.push(path.clone());
+            self.resolve(&mut include.elements, Some(&path), &mut sections.clone())?;
+            self.stack.pop();
         }
+
+        Ok(())
+    }
+}
+
+/// A variable can be declared only once between the program and the
+/// included files, and the name of a variable cannot be the name of a section
+/// or an alias of other variable. A variable has one clause by profile.
+fn check_duplicates(program: &Program) -> Result<(), Box<dyn Error>> {
+    let document = match program.ast.as_document() {
+        Some(document) => document,
+        None => return Ok(()),
     };
 
-    display_program_error(result_program, source, location_val)
+    let variables = document.variables();
+    let sections: BTreeSet<String> = document
+        .section_keys()
+        .iter()
+        .map(|key| key.join("."))
+        .collect();
+
+    let mut declared = BTreeMap::new();
+    let mut errors = vec![];
+
+    for variable in variables.iter() {
+        let env_name = variable.env_name();
+
+        if sections.contains(&variable.key()) {
+            errors.push(ParseError::new(
+                format!("{} is already declared as a section", variable.key()),
+                variable.span,
+            ));
+            continue;
+        }
+
+        match declared.get(&env_name) {
+            Some(first_span) => errors.push(ParseError::new(
+                format!(
+                    "{} is already declared at {}",
+                    env_name,
+                    position(program, *first_span)
+                ),
+                variable.span,
+            )),
+            None => {
+                declared.insert(env_name, variable.span);
+            }
+        }
+    }
+
+    for variable in variables.iter() {
+        for (index, profile) in variable.profiles.iter().enumerate() {
+            if variable.profiles[..index]
+                .iter()
+                .any(|previous| previous.name.eq_ignore_ascii_case(&profile.name))
+            {
+                errors.push(ParseError::new(
+                    format!(
+                        "{}: the profile {} is already declared",
+                        variable.env_name(),
+                        profile.name
+                    ),
+                    profile.span,
+                ));
+            }
+        }
+    }
+
+    for variable in variables {
+        for (alias, span) in Parser::aliases(variable) {
+            match declared.get(&alias) {
+                Some(first_span) => errors.push(ParseError::new(
+                    format!(
+                        "alias {} is already declared at {}",
+                        alias,
+                        position(program, *first_span)
+                    ),
+                    span,
+                )),
+                None => {
+                    declared.insert(alias, span);
+                }
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        do yeet ParseErrors::new(errors)
+    }
+
+    Ok(())
+}
+
+/// The variables named by the constraints must be declared
+fn check_constraints(program: &Program) -> Result<(), Box<dyn Error>> {
+    let document = match program.ast.as_document() {
+        Some(document) => document,
+        None => return Ok(()),
+    };
+
+    let env_names: Vec<String> = document
+        .variables()
+        .into_iter()
+        .map(|variable| variable.env_name())
+        .collect();
+    let mut errors = vec![];
+
+    for constraint in document.constraints() {
+        for constraint_variable in constraint
+            .variables
+            .iter()
+            .chain(constraint.condition.as_ref())
+        {
+            if document
+                .find_variable_from(&constraint.path, &constraint_variable.name)
+                .is_some()
+            {
+                continue;
+            }
+
+            let suggestion = did_you_mean(
+                &constraint_variable.name,
+                env_names.iter().map(String::as_str),
+            )
+            .map(|suggestion| format!(", did you mean {}?", suggestion))
+            .unwrap_or_default();
+
+            errors.push(ParseError::new(
+                format!("{} is not declared{}", constraint_variable.name, suggestion),
+                constraint_variable.span,
+            ));
+        }
+    }
+
+    if !errors.is_empty() {
+        do yeet ParseErrors::new(errors)
+    }
+
+    Ok(())
 }
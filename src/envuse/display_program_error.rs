@@ -1,5 +1,6 @@
+use super::source_file::SourceFile;
 use crate::{
-    errors::{parser_error::ParseError, program_error::ProgramError},
+    errors::{parse_errors::ParseErrors, parser_error::ParseError, program_error::ProgramError},
     syntax_error::SyntaxError,
 };
 
@@ -9,6 +10,17 @@ pub fn display_program_error<A, T: ToString>(
     result: Result<A, Box<dyn Error>>,
     source: T,
     location_val: Option<String>,
+) -> Result<A, Box<dyn Error>> {
+    display_program_error_with_files(result, source, location_val, &[])
+}
+
+/// Same as [`display_program_error`] but the spans inside of the included
+/// `files` are shown with the source of the file
+pub fn display_program_error_with_files<A, T: ToString>(
+    result: Result<A, Box<dyn Error>>,
+    source: T,
+    location_val: Option<String>,
+    files: &[SourceFile],
 ) -> Result<A, Box<dyn Error>> {
     match result {
         Err(error) if error.is::<SyntaxError>() => {
@@ -18,6 +30,7 @@ pub fn display_program_error<A, T: ToString>(
                 span: Some(syntax_error.span.clone()),
                 source: source.to_string(),
                 location: location_val,
+                files: files.to_vec(),
                 cause: Some(error),
             }
         }
@@ -28,6 +41,25 @@ pub fn display_program_error<A, T: ToString>(
                 span: Some(syntax_error.span.clone()),
                 source: source.to_string(),
                 location: location_val,
+                files: files.to_vec(),
+                cause: Some(error),
+            }
+        }
+        Err(error) if error.is::<ParseErrors>() => {
+            let parse_errors = error.downcast_ref::<ParseErrors>().unwrap();
+            let messages: Vec<String> = parse_errors
+                .errors
+                .iter()
+                .map(|parse_error| parse_error.message.as_str())
+                .chain(parse_errors.messages.iter().map(String::as_str))
+                .map(|message| format!("ParseError: {}", message))
+                .collect();
+            do yeet ProgramError {
+                message: messages.join("\n"),
+                span: parse_errors.errors.first().map(|e| e.span),
+                source: source.to_string(),
+                location: location_val,
+                files: files.to_vec(),
                 cause: Some(error),
             }
         }
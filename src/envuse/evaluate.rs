@@ -1 +1,588 @@
-pub struct Evaluate;
+use super::display_program_error::display_program_error_with_files;
+use super::evaluate_options::{ErrorPolicy, EvaluateOptions};
+use super::program::Program;
+use super::source_file::{locate, position, SourceFile};
+use super::warning::Warning;
+use crate::errors::deserialize_error::DeserializeError;
+use crate::errors::parse_errors::ParseErrors;
+use crate::errors::parser_error::ParseError;
+use crate::errors::program_error::ProgramError;
+use crate::parser::ast::{ConstraintKind, ConstraintVariable, Document, Variable};
+use crate::parser::span::Span;
+use crate::transformers::parser::Parser;
+use crate::transformers::value_deserializer::{from_value, from_values};
+use crate::transformers::value_types::ValueType;
+use crate::utils::did_you_mean::did_you_mean;
+use crate::utils::display_syntax::DisplaySyntax;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use std::error::Error;
+
+/// Engine to read the variables of a program. The same engine can be used
+/// with many programs.
+pub struct Evaluate {
+    pub options: EvaluateOptions,
+}
+
+impl Evaluate {
+    pub fn new(options: EvaluateOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn evaluate(
+        &self,
+        program: &Program,
+    ) -> Result<BTreeMap<String, ValueType>, Box<dyn std::error::Error>> {
+        display_program_error_with_files(
+            self.evaluate_unwrap(program),
+            program.source.clone(),
+            program.location.clone(),
+            &program.files,
+        )
+        .map_err(|error| redact_error(program, error))
+    }
+
+    /// Evaluate the program and deserialize the values into `T`. The errors
+    /// are reported with the span of the variable.
+    pub fn deserialize<T: DeserializeOwned>(
+        &self,
+        program: &Program,
+    ) -> Result<T, Box<dyn std::error::Error>> {
+        let values = self.evaluate(program)?;
+
+        display_program_error_with_files(
+            self.deserialize_unwrap(program, values),
+            program.source.clone(),
+            program.location.clone(),
+            &program.files,
+        )
+        .map_err(|error| redact_error(program, error))
+    }
+
+    /// Remove the value of a variable from the evaluated values and
+    /// deserialize it into `T`, e.g. to read a field of a struct. The key is
+    /// the names of the sections and the name of the variable. The errors
+    /// are reported with the span of the variable.
+    pub fn deserialize_variable<T: DeserializeOwned>(
+        &self,
+        program: &Program,
+        values: &mut BTreeMap<String, ValueType>,
+        key: &[&str],
+    ) -> Result<T, Box<dyn std::error::Error>> {
+        let value = ValueType::take(values, key).unwrap_or(ValueType::Null);
+        let result = from_value(value).map_err(|mut error| {
+            error.key = Some(key.join("."));
+            self.deserialize_error(program, error)
+        });
+
+        display_program_error_with_files(
+            result,
+            program.source.clone(),
+            program.location.clone(),
+            &program.files,
+        )
+        .map_err(|error| redact_error(program, error))
+    }
+
+    /// Evaluate the program without profile and with each profile, e.g. to
+    /// check in a pipeline that every profile resolves with the environment.
+    /// The errors of a profile show the profile where they were found, an
+    /// error already found without profile is not repeated.
+    pub fn validate_profiles(&self, program: &Program) -> Result<(), Box<dyn Error>> {
+        let mut errors: Vec<ParseError> = vec![];
+        let mut messages: Vec<String> = vec![];
+
+        let profiles = [None]
+            .into_iter()
+            .chain(program.profiles().into_iter().map(Some));
+
+        for profile in profiles {
+            let mut options = self.options.clone();
+            options.profile = profile.clone();
+
+            let error = match Evaluate::new(options).evaluate_unwrap(program) {
+                Ok(_) => continue,
+                Err(error) => error,
+            };
+
+            let (profile_errors, profile_messages) =
+                if let Some(parse_errors) = error.downcast_ref::<ParseErrors>() {
+                    let profile_errors = parse_errors
+                        .errors
+                        .iter()
+                        .map(|parse_error| (parse_error.message.clone(), parse_error.span))
+                        .collect();
+                    (profile_errors, parse_errors.messages.clone())
+                } else if let Some(parse_error) = error.downcast_ref::<ParseError>() {
+                    (
+                        vec![(parse_error.message.clone(), parse_error.span)],
+                        vec![],
+                    )
+                } else {
+                    (vec![], vec![error.to_string()])
+                };
+
+            // The errors without profile are the first ones and are not labeled
+            let label = |message: &str| match &profile {
+                Some(profile) => format!("[{}] {}", profile, message),
+                None => message.to_string(),
+            };
+
+            for (message, span) in profile_errors {
+                let reported = errors
+                    .iter()
+                    .any(|reported| reported.message == message && reported.span == span);
+
+                if !reported {
+                    errors.push(ParseError::new(label(&message), span));
+                }
+            }
+
+            for message in profile_messages {
+                if !messages.contains(&message) {
+                    messages.push(label(&message));
+                }
+            }
+        }
+
+        let result: Result<(), Box<dyn Error>> = if errors.is_empty() && messages.is_empty() {
+            Ok(())
+        } else {
+            Err(Box::new(ParseErrors::with_messages(errors, messages)))
+        };
+
+        display_program_error_with_files(
+            result,
+            program.source.clone(),
+            program.location.clone(),
+            &program.files,
+        )
+        .map_err(|error| redact_error(program, error))
+    }
+
+    /// Issues found reading the variables of the program that are not
+    /// errors, e.g. a variable read from a deprecated alias
+    pub fn warnings(&self, program: &Program) -> Vec<Warning> {
+        let document = match program.ast.as_document() {
+            Some(document) => document,
+            None => return vec![],
+        };
+
+        let mut warnings = vec![];
+
+        for variable in document.variables() {
+            if let Some((env_key, span)) = Parser::matched_alias(&self.options, variable) {
+                warnings.push(Warning::new(
+                    program,
+                    format!(
+                        "{} is read from the deprecated alias {}, rename it to {}",
+                        variable.env_name(),
+                        env_key,
+                        Parser::env_names(&self.options, variable)[0]
+                    ),
+                    span,
+                ));
+            }
+        }
+
+        warnings
+    }
+
+    fn deserialize_unwrap<T: DeserializeOwned>(
+        &self,
+        program: &Program,
+        values: BTreeMap<String, ValueType>,
+    ) -> Result<T, Box<dyn std::error::Error>> {
+        from_values(values).map_err(|error| self.deserialize_error(program, error))
+    }
+
+    /// Report the error on the variable of its key, the details are hidden
+    /// for the sensitive variables
+    fn deserialize_error(&self, program: &Program, error: DeserializeError) -> Box<dyn Error> {
+        let variable = match (&error.key, program.ast.as_document()) {
+            (Some(key), Some(document)) => document.find_variable_by_key(key),
+            _ => None,
+        };
+
+        let parse_error = match variable {
+            Some(variable) if Parser::is_sensitive(variable) => ParseError::new(
+                format!(
+                    "{}: the value cannot be deserialized, the details are hidden because the variable is sensitive",
+                    Parser::env_key(&self.options, variable)
+                ),
+                variable.span,
+            ),
+            Some(variable) => ParseError::new(
+                format!("{}: {}", Parser::env_key(&self.options, variable), error.message),
+                variable.span,
+            ),
+            None => {
+                return Box::new(ProgramError::from((
+                    program,
+                    format!("DeserializeError: {}", error.message).as_str(),
+                )))
+            }
+        };
+
+        Box::new(parse_error)
+    }
+
+    fn evaluate_unwrap(
+        &self,
+        program: &Program,
+    ) -> Result<BTreeMap<String, ValueType>, Box<dyn std::error::Error>> {
+        let document = match program.ast.as_document() {
+            Some(document) => document,
+            _ => {
+                do yeet ProgramError::from((
+                    program,
+                    "AST Parser error, the ast expressions is not Expression::Document",
+                ))
+            }
+        };
+
+        if let Some(profile) = &self.options.profile {
+            let profiles = program.profiles();
+
+            if !profiles
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(profile))
+            {
+                let suggestion = did_you_mean(profile, profiles.iter().map(String::as_str))
+                    .map(|suggestion| format!(", did you mean {}?", suggestion))
+                    .unwrap_or_default();
+
+                do yeet ProgramError::from((
+                    program,
+                    format!(
+                        "ProfileError: the profile {} is not declared{}",
+                        profile, suggestion
+                    )
+                    .as_str(),
+                ))
+            }
+        }
+
+        let mut configs: BTreeMap<String, ValueType> = BTreeMap::new();
+        let mut errors = vec![];
+
+        for key in document.section_keys() {
+            section_values(&mut configs, &key);
+        }
+
+        for variable in document.variables() {
+            match Parser::to_parse_variable(&self.options, document, variable) {
+                Ok(value) => insert_value(&mut configs, &variable.key_path(), value),
+                Err(error) => match self.options.error_policy {
+                    // A cycle of the interpolation is the same error on each variable of the cycle
+                    ErrorPolicy::Collect => {
+                        let reported = errors.iter().any(|reported: &ParseError| {
+                            reported.span == error.span && reported.message == error.message
+                        });
+
+                        if !reported {
+                            errors.push(error)
+                        }
+                    }
+                    ErrorPolicy::Abort => do yeet error,
+                    ErrorPolicy::Skip => continue,
+                },
+            }
+        }
+
+        errors.extend(self.violated_constraints(program, document, &configs));
+
+        let (undeclared_errors, undeclared_messages) = self.undeclared_envs(document);
+        errors.extend(undeclared_errors);
+
+        if !errors.is_empty() || !undeclared_messages.is_empty() {
+            do yeet ParseErrors::with_messages(errors, undeclared_messages)
+        }
+
+        Ok(configs)
+    }
+
+    /// Errors of the constraints not satisfied by the values, on the
+    /// constraint and on each variable involved. A constraint with a variable
+    /// that could not be evaluated is not checked.
+    fn violated_constraints(
+        &self,
+        program: &Program,
+        document: &Document,
+        configs: &BTreeMap<String, ValueType>,
+    ) -> Vec<ParseError> {
+        let mut errors = vec![];
+
+        for constraint in document.constraints() {
+            let resolve = |constraint_variable: &ConstraintVariable| {
+                let variable =
+                    document.find_variable_from(&constraint.path, &constraint_variable.name)?;
+                let key = variable.key_path();
+                let key: Vec<&str> = key.iter().map(String::as_str).collect();
+                let value = ValueType::get(configs, &key)?;
+
+                Some((variable, Parser::env_key(&self.options, variable), value))
+            };
+
+            let variables: Option<Vec<(&Variable, String, &ValueType)>> =
+                constraint.variables.iter().map(resolve).collect();
+            let variables = match variables {
+                Some(variables) => variables,
+                None => continue,
+            };
+            let at_constraint = format!(
+                "see the constraint at {}",
+                position(program, constraint.span)
+            );
+
+            match constraint.kind {
+                ConstraintKind::Require => {
+                    let (condition, condition_key, condition_value) =
+                        match constraint.condition.as_ref().and_then(resolve) {
+                            Some(condition) => condition,
+                            None => continue,
+                        };
+                    // A sensitive condition is checked by its value
+                    let condition_value = match condition_value {
+                        ValueType::Secret(secret) => secret.expose(),
+                        condition_value => condition_value,
+                    };
+                    let state = match condition_value {
+                        ValueType::Null | ValueType::Boolean(false) => continue,
+                        ValueType::Boolean(true) => "true",
+                        _ => "set",
+                    };
+
+                    let missing: Vec<&(&Variable, String, &ValueType)> = variables
+                        .iter()
+                        .filter(|(_, _, value)| matches!(value, ValueType::Null))
+                        .collect();
+                    if missing.is_empty() {
+                        continue;
+                    }
+
+                    errors.push(ParseError::new(
+                        format!(
+                            "the constraint requires {} when {} is {}",
+                            join_keys(missing.iter().map(|(_, key, _)| key)),
+                            condition_key,
+                            state
+                        ),
+                        constraint.span,
+                    ));
+                    errors.extend(missing.iter().map(|(variable, key, _)| {
+                        ParseError::new(
+                            format!("{} is not set, {}", key, at_constraint),
+                            variable.span,
+                        )
+                    }));
+                    errors.push(ParseError::new(
+                        format!("{} is {}, {}", condition_key, state, at_constraint),
+                        condition.span,
+                    ));
+                }
+                ConstraintKind::OneOf => {
+                    let set: Vec<&(&Variable, String, &ValueType)> = variables
+                        .iter()
+                        .filter(|(_, _, value)| !matches!(value, ValueType::Null))
+                        .collect();
+                    let all_keys = join_keys(variables.iter().map(|(_, key, _)| key));
+
+                    if set.is_empty() {
+                        errors.push(ParseError::new(
+                            format!(
+                                "the constraint requires exactly one of {}, none is set",
+                                all_keys
+                            ),
+                            constraint.span,
+                        ));
+                        errors.extend(variables.iter().map(|(variable, key, _)| {
+                            ParseError::new(
+                                format!("{} is not set, {}", key, at_constraint),
+                                variable.span,
+                            )
+                        }));
+                    } else if set.len() > 1 {
+                        errors.push(ParseError::new(
+                            format!(
+                                "the constraint requires exactly one of {}, but {} are set",
+                                all_keys,
+                                join_keys(set.iter().map(|(_, key, _)| key))
+                            ),
+                            constraint.span,
+                        ));
+                        errors.extend(set.iter().map(|(variable, key, _)| {
+                            ParseError::new(
+                                format!("{} is set, {}", key, at_constraint),
+                                variable.span,
+                            )
+                        }));
+                    }
+                }
+            }
+        }
+
+        errors
+    }
+
+    /// Variables of the environment with the prefix `strict_prefix` that are
+    /// not declared. The variables with a similar declared name are reported
+    /// on the declaration of this name.
+    fn undeclared_envs(&self, document: &Document) -> (Vec<ParseError>, Vec<String>) {
+        let strict_prefix = match &self.options.strict_prefix {
+            Some(strict_prefix) => strict_prefix,
+            None => return (vec![], vec![]),
+        };
+
+        let case_insensitive = self.options.case_insensitive;
+        let matches = |a: &str, b: &str| a == b || (case_insensitive && a.eq_ignore_ascii_case(b));
+        let starts_with = |key: &str, prefix: &str| {
+            key.get(..prefix.len())
+                .map_or(false, |start| matches(start, prefix))
+        };
+
+        let declared: Vec<(String, &Variable)> = document
+            .variables()
+            .into_iter()
+            .flat_map(|variable| {
+                Parser::env_names(&self.options, variable)
+                    .into_iter()
+                    .map(move |env_name| (env_name, variable))
+            })
+            .collect();
+
+        let mut errors = vec![];
+        let mut messages = vec![];
+
+        for (key, _) in self
+            .options
+            .envs
+            .iter()
+            .filter(|(_, value)| value.is_some())
+        {
+            if !starts_with(key, strict_prefix)
+                || declared.iter().any(|(env_name, _)| matches(key, env_name))
+            {
+                continue;
+            }
+
+            let suggestion =
+                did_you_mean(key, declared.iter().map(|(env_name, _)| env_name.as_str())).and_then(
+                    |suggestion| declared.iter().find(|(env_name, _)| env_name == suggestion),
+                );
+
+            match suggestion {
+                Some((env_name, variable)) => errors.push(ParseError::new(
+                    format!("{} is not declared, did you mean {}?", key, env_name),
+                    variable.span,
+                )),
+                None => messages.push(format!("{} is not declared", key)),
+            }
+        }
+
+        (errors, messages)
+    }
+}
+
+fn join_keys<'a>(keys: impl Iterator<Item = &'a String>) -> String {
+    keys.map(String::as_str).collect::<Vec<&str>>().join(", ")
+}
+
+/// Insert the value inside of the maps of its sections, the key is the
+/// names of the sections and the name of the variable
+fn insert_value(configs: &mut BTreeMap<String, ValueType>, key: &[String], value: ValueType) {
+    if let Some((name, sections)) = key.split_last() {
+        if let Some(values) = section_values(configs, sections) {
+            values.insert(name.to_string(), value);
+        }
+    }
+}
+
+/// Map of the values of a section, created with the maps of its parents if
+/// they are missing
+fn section_values<'a>(
+    configs: &'a mut BTreeMap<String, ValueType>,
+    sections: &[String],
+) -> Option<&'a mut BTreeMap<String, ValueType>> {
+    let (section, sections) = match sections.split_first() {
+        Some(split) => split,
+        None => return Some(configs),
+    };
+
+    let entry = configs
+        .entry(section.to_string())
+        .or_insert_with(|| ValueType::Map(BTreeMap::new()));
+
+    match entry {
+        ValueType::Map(values) => section_values(values, sections),
+        _ => None,
+    }
+}
+
+/// Hide the default values of the sensitive variables in the source shown
+/// by the error
+fn redact_error(program: &Program, error: Box<dyn Error>) -> Box<dyn Error> {
+    match program.ast.as_document() {
+        Some(document) => redact(error, &sensitive_spans(document)),
+        None => error,
+    }
+}
+
+/// Spans of the default values of the sensitive variables, in every profile
+pub(crate) fn sensitive_spans(document: &Document) -> Vec<Span> {
+    document
+        .variables()
+        .into_iter()
+        .filter(|variable| Parser::is_sensitive(variable))
+        .flat_map(|variable| {
+            let profile_default_values = variable
+                .profiles
+                .iter()
+                .filter_map(|profile| profile.default_value.as_ref().as_ref());
+
+            variable
+                .default_value
+                .as_ref()
+                .as_ref()
+                .into_iter()
+                .chain(profile_default_values)
+        })
+        .map(|expression| expression.to_span())
+        .collect()
+}
+
+/// Hide the spans in the source and the files shown by the error
+pub(crate) fn redact(mut error: Box<dyn Error>, spans: &[Span]) -> Box<dyn Error> {
+    if let Some(program_error) = error.downcast_mut::<ProgramError>() {
+        program_error.source = DisplaySyntax::redact_payload(&program_error.source, spans);
+
+        for file in program_error.files.iter_mut() {
+            file.source = redact_file(file, spans);
+        }
+    }
+
+    error
+}
+
+/// Source of the file with the spans inside of it hidden
+pub(crate) fn redact_file(file: &SourceFile, spans: &[Span]) -> String {
+    let file_spans: Vec<Span> = spans
+        .iter()
+        .filter_map(|span| locate(std::slice::from_ref(file), *span))
+        .map(|(_, span)| span)
+        .collect();
+
+    DisplaySyntax::redact_payload(&file.source, &file_spans)
+}
+
+impl Default for Evaluate {
+    fn default() -> Self {
+        Self::new(EvaluateOptions::new())
+    }
+}
+
+impl From<EvaluateOptions> for Evaluate {
+    fn from(options: EvaluateOptions) -> Self {
+        Self::new(options)
+    }
+}
@@ -1 +1,113 @@
-pub struct EvaluateOptions;
+use super::to_custom_transformers::ToCustomTransformers;
+use super::to_envs::ToEnvs;
+use crate::transformers::kinds::boolean_transform::BooleanTransform;
+use crate::transformers::kinds::byte_size_transform::ByteSizeTransform;
+use crate::transformers::kinds::duration_transform::DurationTransform;
+use crate::transformers::kinds::enum_transform::EnumTransform;
+use crate::transformers::kinds::float_transform::FloatTransform;
+use crate::transformers::kinds::host_transform::HostTransform;
+use crate::transformers::kinds::ip_addr_transform::IpAddrTransform;
+use crate::transformers::kinds::number_transform::NumberTransform;
+use crate::transformers::kinds::port_transform::PortTransform;
+use crate::transformers::kinds::socket_addr_transform::SocketAddrTransform;
+use crate::transformers::kinds::string_transform::StringTransform;
+use crate::transformers::kinds::uint_transform::UIntTransform;
+use crate::transformers::kinds::url_transform::UrlTransform;
+use crate::transformers::transformer_list::TransformerList;
+use std::collections::BTreeMap;
+
+/// Describe what to do when a variable cannot be evaluated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Evaluate every variable and report all errors found together
+    Collect,
+    /// Stop the evaluation with the first error found
+    Abort,
+    /// Leave out of the result the variables with errors
+    Skip,
+}
+
+/// Options used by [`Evaluate`](super::evaluate::Evaluate) to read the variables of a program
+#[derive(Clone)]
+pub struct EvaluateOptions {
+    /// Values to read the variables
+    pub envs: BTreeMap<String, Option<String>>,
+    /// Transformers available by type name (in lowercase)
+    pub transformers: TransformerList,
+    /// If is `false` the unknown types are read as custom types instead of fail
+    pub strict: bool,
+    /// Prefix prepended to the name of each variable to find its value
+    pub prefix: Option<String>,
+    /// If is `true` the name of the variable (with the prefix) is found in
+    /// the environment ignoring the case, e.g. `mysvc_port` for `MYSVC_PORT`
+    pub case_insensitive: bool,
+    pub error_policy: ErrorPolicy,
+    /// Profile to read the default values, e.g. `dev` reads `@dev = "..."`
+    pub profile: Option<String>,
+    /// Prefix of the environment variables that belong to the program. If is
+    /// set, a variable of the environment with this prefix that is not
+    /// declared is an error, e.g. a typo like `MYSVC_PROT`
+    pub strict_prefix: Option<String>,
+    /// If is `true` the references `${NAME}` are replaced in the values of
+    /// the environment too, not only in the default values
+    pub interpolate_envs: bool,
+}
+
+impl EvaluateOptions {
+    pub fn new() -> Self {
+        let mut transformers = TransformerList::new();
+
+        transformers.insert("str", Box::new(StringTransform));
+        transformers.insert("string", Box::new(StringTransform));
+        transformers.insert("secret", Box::new(StringTransform));
+        transformers.insert("url", Box::new(UrlTransform));
+        transformers.insert("int", Box::new(NumberTransform));
+        transformers.insert("integer", Box::new(NumberTransform));
+        transformers.insert("number", Box::new(NumberTransform));
+        transformers.insert("uint", Box::new(UIntTransform));
+        transformers.insert("float", Box::new(FloatTransform));
+        transformers.insert("bool", Box::new(BooleanTransform));
+        transformers.insert("boolean", Box::new(BooleanTransform));
+        transformers.insert("port", Box::new(PortTransform));
+        transformers.insert("ipaddr", Box::new(IpAddrTransform));
+        transformers.insert("socketaddr", Box::new(SocketAddrTransform));
+        transformers.insert("host", Box::new(HostTransform));
+        transformers.insert("bytesize", Box::new(ByteSizeTransform));
+        transformers.insert("duration", Box::new(DurationTransform));
+        transformers.insert("enum", Box::new(EnumTransform));
+        transformers.insert("oneof", Box::new(EnumTransform));
+
+        Self {
+            envs: BTreeMap::new(),
+            transformers,
+            strict: true,
+            prefix: None,
+            case_insensitive: false,
+            error_policy: ErrorPolicy::Collect,
+            strict_prefix: None,
+            profile: None,
+            interpolate_envs: false,
+        }
+    }
+
+    /// Read the variables from the environment names with the prefix, e.g.
+    /// `PORT` from `MYSVC_PORT`
+    pub fn set_prefix<T: ToString>(&mut self, prefix: T) {
+        self.prefix = Some(prefix.to_string());
+    }
+
+    pub fn set_envs<T: ToEnvs>(&mut self, values: T) {
+        self.envs = values.to_envs();
+    }
+
+    pub fn insert_custom_transformers<T: ToCustomTransformers>(&mut self, custom_transformers: T) {
+        self.transformers
+            .extend(custom_transformers.to_custom_transformers());
+    }
+}
+
+impl Default for EvaluateOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
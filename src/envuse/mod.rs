@@ -2,8 +2,9 @@ pub mod create_program;
 pub mod display_program_error;
 pub mod evaluate;
 pub mod evaluate_options;
-pub mod parse_options;
 pub mod program;
+pub mod source_file;
 pub mod to_custom_transformers;
 pub mod to_envs;
 pub mod to_optional_string;
+pub mod warning;
@@ -1,5 +0,0 @@
-use std::collections::BTreeMap;
-
-pub struct ParseOptions {
-    pub environment_values: BTreeMap<String, String>,
-}
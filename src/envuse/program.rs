@@ -1,15 +1,11 @@
-use crate::errors::program_error::ProgramError;
-use crate::transformers::kinds::custom_transform::CustomTransform;
-
 use super::super::parser::ast::Expression;
-use super::super::transformers::kinds::boolean_transform::BooleanTransform;
-use super::super::transformers::kinds::number_transform::NumberTransform;
-use super::super::transformers::kinds::string_transform::StringTransform;
-use super::super::transformers::parser::Parser;
-use super::super::transformers::transformer_list::TransformerList;
 use super::super::transformers::value_types::ValueType;
+use super::evaluate::Evaluate;
+use super::evaluate_options::EvaluateOptions;
+use super::source_file::SourceFile;
 use super::to_custom_transformers::ToCustomTransformers;
-use super::{super::envuse::to_envs::ToEnvs, display_program_error::display_program_error};
+use super::to_envs::ToEnvs;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
@@ -19,9 +15,14 @@ pub struct Program {
     pub location: Option<String>,
     pub source: String,
     pub ast: Expression,
+    /// Files included by the program, in the order they were read
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<SourceFile>,
 }
 
 impl Program {
+    /// Read the variables with the default options. To reuse a configuration
+    /// see [`Evaluate`].
     pub fn parse<T, D>(
         &self,
         values: T,
@@ -31,63 +32,45 @@ impl Program {
         T: ToEnvs,
         D: ToCustomTransformers,
     {
-        display_program_error(
-            self.parse_unwrap(values, custom_transformers),
-            self.source.clone(),
-            self.location.clone(),
-        )
-    }
-
-    fn parse_unwrap<T, D>(
-        &self,
-        values: T,
-        custom_transformers: D,
-    ) -> Result<BTreeMap<String, ValueType>, Box<dyn std::error::Error>>
-    where
-        T: ToEnvs,
-        D: ToCustomTransformers,
-    {
-        let mut transformer_list: TransformerList = Default::default();
+        let mut options = EvaluateOptions::new();
+        options.set_envs(values);
+        options.insert_custom_transformers(custom_transformers);
 
-        // transformer_list.insert("unknown", Box::new(StringTransform));
-        transformer_list.insert("str", Box::new(StringTransform));
-        transformer_list.insert("string", Box::new(StringTransform));
-        transformer_list.insert("int", Box::new(NumberTransform));
-        transformer_list.insert("number", Box::new(NumberTransform));
-        transformer_list.insert("bool", Box::new(BooleanTransform));
-        transformer_list.insert("boolean", Box::new(BooleanTransform));
-
-        for key in custom_transformers.to_vec() {
-            transformer_list.insert(key.to_lowercase(), Box::new(CustomTransform));
-        }
+        Evaluate::new(options).evaluate(self)
+    }
 
-        let envs_values = values.to_envs();
+    /// Names of the profiles used by the variables, e.g. `dev` in
+    /// `LOG_LEVEL = "info" @dev = "debug"`, in order of appearance
+    pub fn profiles(&self) -> Vec<String> {
+        let mut profiles: Vec<String> = vec![];
 
-        let document = match self.ast.as_document() {
-            Some(document) => document,
-            _ => {
-                do yeet ProgramError::from((
-                    self,
-                    "AST Parser error, the ast expressions is not Expression::Document",
-                ))
+        if let Some(document) = self.ast.as_document() {
+            for profile in document
+                .variables()
+                .into_iter()
+                .flat_map(|variable| variable.profiles.iter())
+            {
+                if !profiles
+                    .iter()
+                    .any(|name| name.eq_ignore_ascii_case(&profile.name))
+                {
+                    profiles.push(profile.name.to_string());
+                }
             }
-        };
-
-        let expressions = &document.elements;
-
-        let mut configs: BTreeMap<String, ValueType> = BTreeMap::new();
-
-        for expression in expressions {
-            let variable = match expression.as_variable() {
-                Some(variable) => variable,
-                _ => do yeet ProgramError::from((self, "Expression is not supported")),
-            };
+        }
 
-            let value = Parser::to_parse_variable(&transformer_list, &variable, &envs_values)?;
+        profiles
+    }
 
-            configs.insert(variable.name.to_string(), value);
-        }
+    /// Read the variables with the default options and deserialize them into `T`
+    pub fn deserialize<T, E>(&self, values: E) -> Result<T, Box<dyn std::error::Error>>
+    where
+        T: DeserializeOwned,
+        E: ToEnvs,
+    {
+        let mut options = EvaluateOptions::new();
+        options.set_envs(values);
 
-        Ok(configs)
+        Evaluate::new(options).deserialize(self)
     }
 }
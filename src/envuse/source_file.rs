@@ -0,0 +1,51 @@
+use super::program::Program;
+use crate::parser::span::Span;
+use serde::{Deserialize, Serialize};
+
+/// File included by a program. The spans of its expressions start at
+/// `offset`, after the source of the program and the previous files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceFile {
+    pub location: String,
+    pub source: String,
+    pub offset: usize,
+}
+
+impl SourceFile {
+    pub fn len(&self) -> usize {
+        self.source.chars().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.source.is_empty()
+    }
+}
+
+/// Find the file that contains the span and the span relative to this file
+pub fn locate(files: &[SourceFile], span: Span) -> Option<(&SourceFile, Span)> {
+    files
+        .iter()
+        .find(|file| span.start >= file.offset && span.start <= file.offset + file.len())
+        .map(|file| {
+            let local_span = Span {
+                start: span.start - file.offset,
+                end: span.end - file.offset,
+            };
+
+            (file, local_span)
+        })
+}
+
+/// Describe the position of a span as `location:line:column`
+pub fn position(program: &Program, span: Span) -> String {
+    let (source, location, span) = match locate(&program.files, span) {
+        Some((file, span)) => (file.source.as_str(), Some(file.location.as_str()), span),
+        None => (program.source.as_str(), program.location.as_deref(), span),
+    };
+
+    let before: Vec<char> = source.chars().take(span.start).collect();
+    let line = before.iter().filter(|ch| **ch == '\n').count() + 1;
+    let column = before.iter().rev().take_while(|ch| **ch != '\n').count() + 1;
+
+    format!("{}:{}:{}", location.unwrap_or("<source>"), line, column)
+}
@@ -1,26 +1,43 @@
-use std::{ptr::NonNull, vec};
+use crate::transformers::kinds::custom_transform::CustomTransform;
+use crate::transformers::transformer_list::TransformerList;
 
+/// Source of the transformers added to the built-in types. A list of names
+/// reads each type as [`ValueType::Custom`](crate::transformers::value_types::ValueType::Custom),
+/// a [`TransformerList`] registers its own transformers.
 pub trait ToCustomTransformers {
-    fn to_vec(self) -> Vec<String>;
+    fn to_custom_transformers(self) -> TransformerList;
+}
+
+fn names_to_transformers<I: IntoIterator<Item = T>, T: ToString>(names: I) -> TransformerList {
+    let mut transformers = TransformerList::new();
+
+    for name in names {
+        transformers.insert(name, Box::new(CustomTransform));
+    }
+
+    transformers
 }
 
 impl<T: ToString> ToCustomTransformers for Option<Vec<T>> {
-    fn to_vec(self) -> Vec<String> {
-        match self {
-            Some(a) => a.iter().map(|s| s.to_string()).collect(),
-            None => vec![],
-        }
+    fn to_custom_transformers(self) -> TransformerList {
+        names_to_transformers(self.unwrap_or_default())
     }
 }
 
 impl<T: ToString> ToCustomTransformers for Vec<T> {
-    fn to_vec(self) -> Vec<String> {
-        self.iter().map(|s| s.to_string()).collect()
+    fn to_custom_transformers(self) -> TransformerList {
+        names_to_transformers(self)
     }
 }
 
 impl<T: ToString, const Z: usize> ToCustomTransformers for [T; Z] {
-    fn to_vec(self) -> Vec<String> {
-        self.iter().map(|s| s.to_string()).collect()
+    fn to_custom_transformers(self) -> TransformerList {
+        names_to_transformers(self)
+    }
+}
+
+impl ToCustomTransformers for TransformerList {
+    fn to_custom_transformers(self) -> TransformerList {
+        self
     }
 }
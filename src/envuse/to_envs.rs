@@ -1,4 +1,5 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+use std::env::{Vars, VarsOs};
 
 use super::to_optional_string::ToOptionalString;
 
@@ -6,20 +7,88 @@ pub trait ToEnvs {
     fn to_envs(self) -> BTreeMap<String, Option<String>>;
 }
 
-impl ToEnvs for BTreeMap<String, Option<String>> {
+/// Source to read the values from the environment of the current process.
+///
+/// Unlike [`std::env::vars`] it does not panic with a variable with an
+/// invalid unicode, the invalid sequences are replaced with `U+FFFD`.
+pub struct ProcessEnvs;
+
+/// Source to read the values from any iterator of key-value pairs, e.g.
+/// `FromIter(std::env::vars().filter(..))`.
+pub struct FromIter<I>(pub I);
+
+fn collect_envs<I, K, V>(iter: I) -> BTreeMap<String, Option<String>>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: ToString,
+    V: ToOptionalString,
+{
+    iter.into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_optional_string()))
+        .collect()
+}
+
+impl<K: ToString, V: ToOptionalString> ToEnvs for BTreeMap<K, V> {
+    fn to_envs(self) -> BTreeMap<String, Option<String>> {
+        collect_envs(self)
+    }
+}
+
+impl<K: ToString, V: ToOptionalString, S> ToEnvs for HashMap<K, V, S> {
     fn to_envs(self) -> BTreeMap<String, Option<String>> {
-        self
+        collect_envs(self)
+    }
+}
+
+impl<K: ToString, V: ToOptionalString> ToEnvs for Vec<(K, V)> {
+    fn to_envs(self) -> BTreeMap<String, Option<String>> {
+        collect_envs(self)
     }
 }
 
 impl<T: ToString, D: ToOptionalString, const Z: usize> ToEnvs for [(T, D); Z] {
     fn to_envs(self) -> BTreeMap<String, Option<String>> {
-        BTreeMap::from(self.map(|t| (t.0.to_string(), t.1.to_optional_string())))
+        collect_envs(self)
     }
 }
 
 impl ToEnvs for Option<BTreeMap<String, Option<String>>> {
     fn to_envs(self) -> BTreeMap<String, Option<String>> {
-        BTreeMap::new()
+        self.unwrap_or_default()
+    }
+}
+
+impl<I, K, V> ToEnvs for FromIter<I>
+where
+    I: Iterator<Item = (K, V)>,
+    K: ToString,
+    V: ToOptionalString,
+{
+    fn to_envs(self) -> BTreeMap<String, Option<String>> {
+        collect_envs(self.0)
+    }
+}
+
+impl ToEnvs for Vars {
+    fn to_envs(self) -> BTreeMap<String, Option<String>> {
+        collect_envs(self)
+    }
+}
+
+impl ToEnvs for VarsOs {
+    fn to_envs(self) -> BTreeMap<String, Option<String>> {
+        self.map(|(key, value)| {
+            (
+                key.to_string_lossy().to_string(),
+                Some(value.to_string_lossy().to_string()),
+            )
+        })
+        .collect()
+    }
+}
+
+impl ToEnvs for ProcessEnvs {
+    fn to_envs(self) -> BTreeMap<String, Option<String>> {
+        std::env::vars_os().to_envs()
     }
 }
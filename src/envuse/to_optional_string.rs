@@ -13,3 +13,9 @@ impl ToOptionalString for &str {
         Some(self.to_string())
     }
 }
+
+impl ToOptionalString for String {
+    fn to_optional_string(self) -> Option<String> {
+        Some(self)
+    }
+}
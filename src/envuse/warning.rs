@@ -0,0 +1,59 @@
+use super::evaluate::{redact_file, sensitive_spans};
+use super::program::Program;
+use super::source_file::locate;
+use crate::parser::span::Span;
+use crate::utils::display_syntax::{DisplaySyntax, DisplaySyntaxDebugOptions};
+use std::fmt;
+
+/// Issue found in the evaluation of a program that does not stop it, e.g. a
+/// variable read from a deprecated alias
+#[derive(Debug, Clone)]
+pub struct Warning {
+    pub message: String,
+    /// Span inside of the file of `location`
+    pub span: Span,
+    pub source: String,
+    pub location: Option<String>,
+}
+
+impl Warning {
+    /// The default values of the sensitive variables are hidden in the source
+    pub fn new<T: ToString>(program: &Program, message: T, span: Span) -> Self {
+        let spans = program
+            .ast
+            .as_document()
+            .map(sensitive_spans)
+            .unwrap_or_default();
+
+        let (source, location, span) = match locate(&program.files, span) {
+            Some((file, span)) => (redact_file(file, &spans), Some(file.location.clone()), span),
+            None => (
+                DisplaySyntax::redact_payload(&program.source, &spans),
+                program.location.clone(),
+                span,
+            ),
+        };
+
+        Self {
+            message: message.to_string(),
+            span,
+            source,
+            location,
+        }
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_options = DisplaySyntaxDebugOptions::new();
+        debug_options.location = self.location.clone();
+
+        let display_syntax = DisplaySyntax::new(format!("Warning: {}", self.message), self.span);
+
+        write!(
+            f,
+            "{}",
+            display_syntax.debug_payload_configurable(&self.source, &debug_options)
+        )
+    }
+}
@@ -0,0 +1,36 @@
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when the values of a program cannot be deserialized
+#[derive(Debug)]
+pub struct DeserializeError {
+    pub message: String,
+    /// Name of the variable where the error was found
+    pub key: Option<String>,
+}
+
+impl DeserializeError {
+    pub fn new<T>(message: T, key: Option<String>) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            message: message.to_string(),
+            key,
+        }
+    }
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message.as_str())
+    }
+}
+
+impl Error for DeserializeError {}
+
+impl serde::de::Error for DeserializeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg, None)
+    }
+}
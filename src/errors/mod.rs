@@ -1,3 +1,6 @@
+pub mod deserialize_error;
+pub mod parse_errors;
 pub mod parser_error;
 pub mod program_error;
 pub mod to_program_error;
+pub mod transform_error;
@@ -0,0 +1,36 @@
+use super::parser_error::ParseError;
+use std::error::Error;
+use std::fmt;
+
+/// Set of errors found while a program is evaluated
+#[derive(Debug)]
+pub struct ParseErrors {
+    pub errors: Vec<ParseError>,
+    /// Errors without a position in the source, e.g. an undeclared variable
+    /// of the environment
+    pub messages: Vec<String>,
+}
+
+impl ParseErrors {
+    pub fn new(errors: Vec<ParseError>) -> Self {
+        Self::with_messages(errors, vec![])
+    }
+
+    pub fn with_messages(errors: Vec<ParseError>, messages: Vec<String>) -> Self {
+        Self { errors, messages }
+    }
+}
+
+impl fmt::Display for ParseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<&str> = self
+            .errors
+            .iter()
+            .map(|e| e.message.as_str())
+            .chain(self.messages.iter().map(String::as_str))
+            .collect();
+        write!(f, "{}", messages.join("\n"))
+    }
+}
+
+impl Error for ParseErrors {}
@@ -1,4 +1,6 @@
 use super::super::envuse::program::Program;
+use super::super::envuse::source_file::{locate, SourceFile};
+use super::parse_errors::ParseErrors;
 use super::parser_error::ParseError;
 use crate::parser::span::Span;
 use crate::syntax_error::SyntaxError;
@@ -12,36 +14,72 @@ pub struct ProgramError {
     pub span: Option<Span>,
     pub source: String,
     pub location: Option<String>,
+    /// Files included by the program, used to show the spans of these files
+    pub files: Vec<SourceFile>,
     pub cause: Option<Box<dyn Error>>,
 }
 
 impl ProgramError {
     pub fn get_message(&self) -> String {
-        let mut debug_options = DisplaySyntaxDebugOptions::new();
-        debug_options.location = self.location.clone();
-
         match &self.cause {
             Some(error) if error.is::<SyntaxError>() => {
                 let syntax_error = error.downcast_ref::<SyntaxError>().unwrap();
-                let display_syntax = DisplaySyntax::new(
-                    format!("SyntaxError: {}", syntax_error.message),
-                    syntax_error.span.clone(),
-                );
 
-                display_syntax.debug_payload_configurable(&self.source, &debug_options)
+                self.display_span(
+                    format!("SyntaxError: {}", syntax_error.message),
+                    syntax_error.span,
+                )
             }
             Some(error) if error.is::<ParseError>() => {
                 let parse_error = error.downcast_ref::<ParseError>().unwrap();
-                let display_syntax = DisplaySyntax::new(
+
+                self.display_span(
                     format!("ParseError: {}", parse_error.message),
-                    parse_error.span.clone(),
-                );
+                    parse_error.span,
+                )
+            }
+            Some(error) if error.is::<ParseErrors>() => {
+                let parse_errors = error.downcast_ref::<ParseErrors>().unwrap();
+                let payloads: Vec<String> = parse_errors
+                    .errors
+                    .iter()
+                    .map(|parse_error| {
+                        self.display_span(
+                            format!("ParseError: {}", parse_error.message),
+                            parse_error.span,
+                        )
+                    })
+                    .chain(
+                        parse_errors
+                            .messages
+                            .iter()
+                            .map(|message| format!("ParseError: {}\n", message)),
+                    )
+                    .collect();
 
-                display_syntax.debug_payload_configurable(&self.source, &debug_options)
+                payloads.join("\n")
             }
             _ => self.message.to_string(),
         }
     }
+
+    /// Show the span with the source of the file that contains it
+    fn display_span(&self, message: String, span: Span) -> String {
+        let mut debug_options = DisplaySyntaxDebugOptions::new();
+
+        let (source, span) = match locate(&self.files, span) {
+            Some((file, span)) => {
+                debug_options.location = Some(file.location.clone());
+                (&file.source, span)
+            }
+            None => {
+                debug_options.location = self.location.clone();
+                (&self.source, span)
+            }
+        };
+
+        DisplaySyntax::new(message, span).debug_payload_configurable(source, &debug_options)
+    }
 }
 
 impl fmt::Display for ProgramError {
@@ -60,6 +98,7 @@ impl From<(&Program, &str)> for ProgramError {
             span: None,
             source: program.source.clone(),
             location: program.location.clone(),
+            files: program.files.clone(),
             cause: None,
         }
     }
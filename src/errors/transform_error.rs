@@ -0,0 +1,45 @@
+use super::super::parser::span::Span;
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by a transformer when a value cannot be converted
+#[derive(Debug)]
+pub struct TransformError {
+    pub message: String,
+    /// Span of the source related to the error, e.g. the value of a type option
+    pub span: Option<Span>,
+}
+
+impl TransformError {
+    pub fn new<T>(message: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            message: message.to_string(),
+            span: None,
+        }
+    }
+
+    pub fn with_span<T>(message: T, span: Span) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            message: message.to_string(),
+            span: Some(span),
+        }
+    }
+}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message.as_str())
+    }
+}
+
+impl Error for TransformError {
+    fn description(&self) -> &str {
+        self.message.as_str()
+    }
+}
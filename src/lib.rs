@@ -7,4 +7,4 @@ pub mod parser;
 pub mod syntax_error;
 pub mod transformers;
 pub mod utils;
-pub use envuse::create_program::create_program;
+pub use envuse::create_program::{create_program, create_program_with_reader};
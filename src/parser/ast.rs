@@ -10,6 +10,102 @@ pub struct Document {
     pub elements: Vec<Expression>,
 }
 
+impl Document {
+    /// Variables of the document, its sections and the files included, in order
+    pub fn variables(&self) -> Vec<&Variable> {
+        let mut variables = vec![];
+        collect_variables(&self.elements, &mut variables);
+        variables
+    }
+
+    /// Find a variable by the name of its environment variable, e.g. `DATABASE_HOST`
+    pub fn find_variable(&self, name: &str) -> Option<&Variable> {
+        self.variables()
+            .into_iter()
+            .find(|variable| variable.env_name() == name)
+    }
+
+    /// Find a variable by its key in the evaluated values, e.g. `DATABASE.HOST`
+    pub fn find_variable_by_key(&self, key: &str) -> Option<&Variable> {
+        self.variables()
+            .into_iter()
+            .find(|variable| variable.key() == key)
+    }
+
+    /// Keys of the sections in the evaluated values, the empty sections
+    /// included, e.g. `["DATABASE", "REPLICA"]` for `replica { ... }` inside
+    /// of `database { ... }`
+    pub fn section_keys(&self) -> Vec<Vec<String>> {
+        let mut keys = vec![];
+        collect_section_keys(&self.elements, &mut vec![], &mut keys);
+        keys
+    }
+
+    /// Constraints of the document, its sections and the files included, in order
+    pub fn constraints(&self) -> Vec<&Constraint> {
+        let mut constraints = vec![];
+        collect_constraints(&self.elements, &mut constraints);
+        constraints
+    }
+
+    /// Find a variable by a name written inside of the sections `path`, e.g.
+    /// in a constraint or a reference `${NAME}`. The name is searched from the
+    /// innermost section to the top of the document, e.g. `HOST` inside of
+    /// `database { ... }` is `DATABASE_HOST` or else `HOST`.
+    pub fn find_variable_from(&self, path: &[String], name: &str) -> Option<&Variable> {
+        (0..=path.len()).rev().find_map(|len| {
+            let env_name: String = path[..len]
+                .iter()
+                .map(|section| format!("{}_", section.to_uppercase()))
+                .chain([name.to_string()])
+                .collect();
+
+            self.find_variable(&env_name)
+        })
+    }
+}
+
+fn collect_variables<'a>(elements: &'a [Expression], variables: &mut Vec<&'a Variable>) {
+    for expression in elements {
+        match expression {
+            Expression::Variable(variable) => variables.push(variable),
+            Expression::Include(include) => collect_variables(&include.elements, variables),
+            Expression::Section(section) => collect_variables(&section.elements, variables),
+            _ => {}
+        }
+    }
+}
+
+fn collect_section_keys(
+    elements: &[Expression],
+    path: &mut Vec<String>,
+    keys: &mut Vec<Vec<String>>,
+) {
+    for expression in elements {
+        match expression {
+            Expression::Include(include) => collect_section_keys(&include.elements, path, keys),
+            Expression::Section(section) => {
+                path.push(section.name.to_uppercase());
+                keys.push(path.clone());
+                collect_section_keys(&section.elements, path, keys);
+                path.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn collect_constraints<'a>(elements: &'a [Expression], constraints: &mut Vec<&'a Constraint>) {
+    for expression in elements {
+        match expression {
+            Expression::Constraint(constraint) => constraints.push(constraint),
+            Expression::Include(include) => collect_constraints(&include.elements, constraints),
+            Expression::Section(section) => collect_constraints(&section.elements, constraints),
+            _ => {}
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Variable {
     pub span: Span,
@@ -17,8 +113,52 @@ pub struct Variable {
     pub name: String,
     pub variable_type: Option<String>,
     pub options_variable_type: Option<BTreeMap<String, Option<Expression>>>,
+    /// Keys of the type options in the declared order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options_order: Vec<String>,
     pub default_value: Box<Option<Expression>>,
     pub nullable: bool,
+    /// Names of the sections that contain the variable, from the outermost
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<String>,
+    /// Default values and requiredness by profile, e.g. `@dev = "debug"`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<Profile>,
+}
+
+impl Variable {
+    /// Clause of the variable for the profile, the name is compared ignoring the case
+    pub fn profile(&self, name: &str) -> Option<&Profile> {
+        self.profiles
+            .iter()
+            .find(|profile| profile.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Name of the environment variable, the sections are prefixed in
+    /// uppercase, e.g. `HOST` inside of `database { ... }` is `DATABASE_HOST`
+    pub fn env_name(&self) -> String {
+        self.path
+            .iter()
+            .map(|section| format!("{}_", section.to_uppercase()))
+            .chain([self.name.to_string()])
+            .collect()
+    }
+
+    /// Key of the variable in the evaluated values by section, the sections
+    /// are in uppercase like in the environment name, e.g. `["DATABASE", "HOST"]`
+    /// for `HOST` inside of `database { ... }`
+    pub fn key_path(&self) -> Vec<String> {
+        self.path
+            .iter()
+            .map(|section| section.to_uppercase())
+            .chain([self.name.to_string()])
+            .collect()
+    }
+
+    /// Key of the variable in the evaluated values, e.g. `DATABASE.HOST`
+    pub fn key(&self) -> String {
+        self.key_path().join(".")
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -33,7 +173,73 @@ pub struct DefaultValue {
     pub value: String,
 }
 
+/// List of default values, e.g. `["a", "b"]`. The elements are
+/// [`Expression::DefaultValue`].
 #[derive(Debug, Serialize, Deserialize)]
+pub struct ListValue {
+    pub span: Span,
+    pub elements: Vec<Expression>,
+}
+
+/// Directive to read the variables of other file, e.g. `@include "shared.envuse"`.
+/// The elements are empty until the program resolves the file.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Include {
+    pub span: Span,
+    pub path: String,
+    pub path_span: Span,
+    pub elements: Vec<Expression>,
+}
+
+/// Clause of a variable for a profile, e.g. `@dev = "debug"` replaces the
+/// default value in the profile `dev` and `@prod required` removes it
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Profile {
+    pub span: Span,
+    pub name: String,
+    pub default_value: Box<Option<Expression>>,
+    pub required: bool,
+}
+
+/// Group of variables under a prefix, e.g. `database { HOST = "localhost" }`
+/// reads the environment variable `DATABASE_HOST`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Section {
+    pub span: Span,
+    pub name: String,
+    pub elements: Vec<Expression>,
+}
+
+/// Rule between variables, e.g. `@require TLS_CERT when TLS_ENABLED` or
+/// `@one_of AWS_PROFILE, AWS_ACCESS_KEY_ID`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Constraint {
+    pub span: Span,
+    pub kind: ConstraintKind,
+    pub variables: Vec<ConstraintVariable>,
+    /// Variable after `when`, only for [`ConstraintKind::Require`]
+    pub condition: Option<ConstraintVariable>,
+    /// Names of the sections that contain the constraint, from the outermost
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConstraintKind {
+    /// The variables are required when the condition is set and is not `false`
+    Require,
+    /// Exactly one of the variables is set
+    OneOf,
+}
+
+/// Name of a variable in a constraint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstraintVariable {
+    pub span: Span,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OptionValue {
     pub span: Span,
     pub value: String,
@@ -46,17 +252,105 @@ pub enum Expression {
     CommentBlock(CommentBlock),
     Variable(Variable),
     DefaultValue(DefaultValue),
+    ListValue(ListValue),
     OptionValue(OptionValue),
+    Include(Include),
+    Section(Section),
+    Constraint(Constraint),
 }
 
 impl Expression {
-    fn to_span(&self) -> Span {
+    pub fn to_span(&self) -> Span {
         match self {
             Expression::Document(Document { span, .. }) => span.clone(),
             Expression::CommentBlock(CommentBlock { span, .. }) => span.clone(),
             Expression::Variable(Variable { span, .. }) => span.clone(),
             Expression::DefaultValue(DefaultValue { span, .. }) => span.clone(),
+            Expression::ListValue(ListValue { span, .. }) => *span,
             Expression::OptionValue(OptionValue { span, .. }) => span.clone(),
+            Expression::Include(Include { span, .. }) => *span,
+            Expression::Section(Section { span, .. }) => *span,
+            Expression::Constraint(Constraint { span, .. }) => *span,
+        }
+    }
+
+    /// Move the spans of the expression and its children, e.g. to place the
+    /// expressions of an included file after the source of the program
+    pub fn shift_spans(&mut self, offset: usize) {
+        let shift = |span: &mut Span| {
+            span.start += offset;
+            span.end += offset;
+        };
+
+        match self {
+            Expression::Document(Document { span, elements, .. }) => {
+                shift(span);
+                elements.iter_mut().for_each(|e| e.shift_spans(offset));
+            }
+            Expression::CommentBlock(CommentBlock { span, .. }) => shift(span),
+            Expression::Variable(variable) => {
+                shift(&mut variable.span);
+                if let Some(comment) = variable.comment.as_mut() {
+                    comment.shift_spans(offset);
+                }
+                if let Some(options) = variable.options_variable_type.as_mut() {
+                    options
+                        .values_mut()
+                        .flatten()
+                        .for_each(|e| e.shift_spans(offset));
+                }
+                if let Some(default_value) = variable.default_value.as_mut() {
+                    default_value.shift_spans(offset);
+                }
+                for profile in variable.profiles.iter_mut() {
+                    shift(&mut profile.span);
+                    if let Some(default_value) = profile.default_value.as_mut() {
+                        default_value.shift_spans(offset);
+                    }
+                }
+            }
+            Expression::DefaultValue(DefaultValue { span, .. }) => shift(span),
+            Expression::ListValue(ListValue { span, elements }) => {
+                shift(span);
+                elements.iter_mut().for_each(|e| e.shift_spans(offset));
+            }
+            Expression::OptionValue(OptionValue { span, .. }) => shift(span),
+            Expression::Include(Include {
+                span,
+                path_span,
+                elements,
+                ..
+            }) => {
+                shift(span);
+                shift(path_span);
+                elements.iter_mut().for_each(|e| e.shift_spans(offset));
+            }
+            Expression::Section(Section { span, elements, .. }) => {
+                shift(span);
+                elements.iter_mut().for_each(|e| e.shift_spans(offset));
+            }
+            Expression::Constraint(constraint) => {
+                shift(&mut constraint.span);
+                constraint
+                    .variables
+                    .iter_mut()
+                    .chain(constraint.condition.as_mut())
+                    .for_each(|variable| shift(&mut variable.span));
+            }
+        }
+    }
+
+    /// Place the variables of the expression inside of the section `name`
+    pub fn nest_in_section(&mut self, name: &str) {
+        match self {
+            Expression::Variable(variable) => variable.path.insert(0, name.to_string()),
+            Expression::Constraint(constraint) => constraint.path.insert(0, name.to_string()),
+            Expression::Document(Document { elements, .. })
+            | Expression::Include(Include { elements, .. })
+            | Expression::Section(Section { elements, .. }) => elements
+                .iter_mut()
+                .for_each(|element| element.nest_in_section(name)),
+            _ => {}
         }
     }
 
@@ -68,6 +362,14 @@ impl Expression {
         }
     }
 
+    pub fn as_comment_block(&self) -> Option<&CommentBlock> {
+        if let Self::CommentBlock(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
     pub fn as_document(&self) -> Option<&Document> {
         if let Self::Document(v) = self {
             Some(v)
@@ -76,6 +378,14 @@ impl Expression {
         }
     }
 
+    pub fn as_option_value(&self) -> Option<&OptionValue> {
+        if let Self::OptionValue(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
     pub fn as_default_value(&self) -> Option<&DefaultValue> {
         if let Self::DefaultValue(v) = self {
             Some(v)
@@ -83,6 +393,38 @@ impl Expression {
             None
         }
     }
+
+    pub fn as_include(&self) -> Option<&Include> {
+        if let Self::Include(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_section(&self) -> Option<&Section> {
+        if let Self::Section(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_list_value(&self) -> Option<&ListValue> {
+        if let Self::ListValue(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_constraint(&self) -> Option<&Constraint> {
+        if let Self::Constraint(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -164,6 +506,9 @@ where
     }
 }
 
+/// Keywords after `@` that start a directive instead of a profile clause
+const DIRECTIVES: [&str; 4] = ["include", "import", "require", "one_of"];
+
 pub struct AST {}
 
 impl AST {
@@ -184,6 +529,11 @@ impl AST {
 
         let elements = Self::parse_expressions_list(token_cursor)?;
 
+        // The list of expressions only stops before a `}` without a section
+        if let Some(token) = token_cursor.select_current() {
+            do yeet SyntaxError::new("Unexpected token close_brace", token.span)
+        }
+
         if let Some(expression) = elements.last() {
             span_end = expression.to_span().end;
         }
@@ -212,16 +562,232 @@ impl AST {
                 vec.push(Self::parse_block_comment(tokens_cursor)?);
                 continue;
             }
+            if token.kind == "keyword" && Self::is_section_start(tokens_cursor) {
+                vec.push(Self::parse_section(tokens_cursor)?);
+                continue;
+            }
             if token.kind == "keyword" {
                 vec.push(Self::parse_variable(tokens_cursor, None)?);
                 continue;
             }
+            if token.kind == "close_brace" {
+                break;
+            }
+            if token.kind == "at" {
+                vec.push(Self::parse_directive(tokens_cursor)?);
+                continue;
+            }
             do yeet SyntaxError::new("Unexpected type", token.span.clone())
         }
 
         Ok(vec)
     }
 
+    /// A section starts with a name followed by `{`, e.g. `database {`
+    fn is_section_start(tokens_cursor: &Cursor<Vec<Token>>) -> bool {
+        let mut steps = 1;
+
+        while let Some(token) = tokens_cursor.select_nexts(steps) {
+            match token.kind.as_str() {
+                "space" | "newline" => steps += 1,
+                kind => return kind == "open_brace",
+            }
+        }
+
+        false
+    }
+
+    fn parse_section(tokens_cursor: &mut Cursor<Vec<Token>>) -> Result<Expression, SyntaxError> {
+        let name_token = tokens_cursor.assert_current_kind(["keyword"])?;
+        let name = name_token.raw.to_string();
+        let span_start = name_token.span.start;
+        tokens_cursor.forward(1);
+        tokens_cursor.forward_some_kind(["space", "newline"]);
+        tokens_cursor.assert_current_kind(["open_brace"])?;
+        tokens_cursor.forward(1);
+
+        let mut elements = Self::parse_expressions_list(tokens_cursor)?;
+        for element in elements.iter_mut() {
+            element.nest_in_section(&name);
+        }
+
+        let span_end = match tokens_cursor.select_current() {
+            Some(token) => token.span.end,
+            None => {
+                do yeet SyntaxError::new(
+                    format!("The section {} is not closed, expected }}", name),
+                    Span {
+                        start: span_start,
+                        end: span_start + name.chars().count(),
+                    },
+                )
+            }
+        };
+        tokens_cursor.forward(1);
+
+        Ok(Expression::Section(Section {
+            span: Span {
+                start: span_start,
+                end: span_end,
+            },
+            name,
+            elements,
+        }))
+    }
+
+    /// Parse a directive, e.g. `@include "shared.envuse"` or
+    /// `@require TLS_CERT when TLS_ENABLED`
+    fn parse_directive(tokens_cursor: &mut Cursor<Vec<Token>>) -> Result<Expression, SyntaxError> {
+        let span_start = tokens_cursor.assert_current_kind(["at"])?.span.start;
+        tokens_cursor.forward(1);
+
+        let directive = tokens_cursor.assert_current_kind(["keyword"])?.clone();
+        tokens_cursor.forward(1);
+
+        match directive.raw.as_str() {
+            "include" | "import" => Self::parse_include(tokens_cursor, span_start),
+            "require" => Self::parse_constraint(tokens_cursor, span_start, ConstraintKind::Require),
+            "one_of" => Self::parse_constraint(tokens_cursor, span_start, ConstraintKind::OneOf),
+            _ if Self::is_profile_clause(tokens_cursor) => Err(SyntaxError::new(
+                format!(
+                    "The profile clause @{} must follow its variable without an empty line",
+                    directive.raw
+                ),
+                directive.span,
+            )),
+            _ => Err(SyntaxError::new(
+                format!(
+                    "Unknown directive @{}, expected @include, @import, @require or @one_of",
+                    directive.raw
+                ),
+                directive.span,
+            )),
+        }
+    }
+
+    /// The tokens after `@name` are the ones of a profile clause, e.g. `= "debug"` or `required`
+    fn is_profile_clause(tokens_cursor: &mut Cursor<Vec<Token>>) -> bool {
+        tokens_cursor.forward_some_kind(["space"]);
+
+        match tokens_cursor.select_current() {
+            Some(token) => token.kind == "equal" || token.raw == "required",
+            None => false,
+        }
+    }
+
+    fn parse_include(
+        tokens_cursor: &mut Cursor<Vec<Token>>,
+        span_start: usize,
+    ) -> Result<Expression, SyntaxError> {
+        tokens_cursor.forward_some_kind(["space"]);
+
+        let path_token = tokens_cursor.assert_current_kind(["string"])?;
+        let path = path_token.raw.to_string();
+        let path_span = path_token.span;
+        tokens_cursor.forward(1);
+
+        Ok(Expression::Include(Include {
+            span: Span {
+                start: span_start,
+                // Include the closing quote of the path
+                end: path_span.end + 1,
+            },
+            path,
+            path_span,
+            elements: vec![],
+        }))
+    }
+
+    /// Parse the variables of a constraint separated by commas and, for
+    /// `@require`, the condition after `when`
+    fn parse_constraint(
+        tokens_cursor: &mut Cursor<Vec<Token>>,
+        span_start: usize,
+        kind: ConstraintKind,
+    ) -> Result<Expression, SyntaxError> {
+        let mut variables = vec![];
+        let mut condition = None;
+
+        loop {
+            tokens_cursor.forward_some_kind(["space"]);
+            let token = tokens_cursor.assert_current_kind(["keyword"])?;
+            variables.push(ConstraintVariable {
+                span: token.span,
+                name: token.raw.to_string(),
+            });
+            tokens_cursor.forward(1);
+            tokens_cursor.forward_some_kind(["space"]);
+
+            if tokens_cursor.assert_current_kind(["comma"]).is_err() {
+                break;
+            }
+            tokens_cursor.forward(1);
+        }
+
+        if kind == ConstraintKind::Require {
+            match tokens_cursor.select_current() {
+                Some(token) if token.kind == "keyword" && token.raw == "when" => {
+                    tokens_cursor.forward(1);
+                    tokens_cursor.forward_some_kind(["space"]);
+                    let token = tokens_cursor.assert_current_kind(["keyword"])?;
+                    condition = Some(ConstraintVariable {
+                        span: token.span,
+                        name: token.raw.to_string(),
+                    });
+                    tokens_cursor.forward(1);
+                }
+                _ => {
+                    do yeet SyntaxError::new(
+                        "Expected when and the name of a variable after the variables of @require",
+                        Span {
+                            start: span_start,
+                            end: variables.last().map_or(span_start, |v| v.span.end),
+                        },
+                    )
+                }
+            }
+        } else if variables.len() < 2 {
+            do yeet SyntaxError::new(
+                "Expected two or more variables for @one_of",
+                Span {
+                    start: span_start,
+                    end: variables[0].span.end,
+                },
+            )
+        }
+
+        tokens_cursor.forward_some_kind(["space"]);
+
+        // The constraint ends with the line, e.g. `@require A when B C` is an error
+        if let Some(token) = tokens_cursor.select_current() {
+            if !["newline", "comment"].contains(&token.kind.as_str()) {
+                do yeet SyntaxError::new(
+                    format!(
+                        "Unexpected {} after the constraint, expected a new line",
+                        token.raw
+                    ),
+                    token.span,
+                )
+            }
+        }
+
+        let span_end = condition
+            .as_ref()
+            .or_else(|| variables.last())
+            .map_or(span_start, |variable| variable.span.end);
+
+        Ok(Expression::Constraint(Constraint {
+            span: Span {
+                start: span_start,
+                end: span_end,
+            },
+            kind,
+            variables,
+            condition,
+            path: vec![],
+        }))
+    }
+
     fn parse_block_comment(
         tokens_cursor: &mut Cursor<Vec<Token>>,
     ) -> Result<Expression, SyntaxError> {
@@ -260,7 +826,7 @@ impl AST {
         });
 
         if let Some(token) = tokens_cursor.select_current() {
-            if token.kind == "keyword" {
+            if token.kind == "keyword" && !Self::is_section_start(tokens_cursor) {
                 return Ok(Self::parse_variable(tokens_cursor, Some(comment))?);
             }
         }
@@ -280,6 +846,7 @@ impl AST {
         let mut span_end: usize = tokens_cursor.select_current().unwrap().span.end;
         let mut variable_type: Option<String> = None;
         let mut options_variable_type: Option<BTreeMap<String, Option<Expression>>> = None;
+        let mut options_order: Vec<String> = vec![];
         let mut default_value: Option<Expression> = None;
         let mut nullable: bool = false;
         tokens_cursor.forward(1);
@@ -304,6 +871,9 @@ impl AST {
 
                 while tokens_cursor.assert_current_kind(["keyword"]).is_ok() {
                     let option_key = tokens_cursor.select_current().unwrap().raw.to_string();
+                    if !options_order.contains(&option_key) {
+                        options_order.push(option_key.clone());
+                    }
                     tokens_cursor.forward(1);
                     tokens_cursor.forward_some_kind(["space", "newline"]);
                     if tokens_cursor.assert_current_kind(["equal"]).is_ok() {
@@ -342,6 +912,15 @@ impl AST {
             default_value = Some(default_value_expression);
         }
 
+        let mut profiles = vec![];
+
+        while let Some(steps) = Self::profile_start(tokens_cursor) {
+            tokens_cursor.forward(steps);
+            let profile = Self::parse_profile(tokens_cursor)?;
+            span_end = profile.span.end;
+            profiles.push(profile);
+        }
+
         Ok(Expression::Variable(Variable {
             span: Span {
                 start: span_start,
@@ -351,11 +930,101 @@ impl AST {
             name: name,
             variable_type,
             options_variable_type,
+            options_order,
             default_value: Box::new(default_value),
             nullable,
+            path: vec![],
+            profiles,
         }))
     }
 
+    /// Steps to the next profile clause of a variable, the clauses can
+    /// continue in the next lines but not after an empty line, e.g.
+    /// `@dev = "debug"`. The directives, e.g. `@include`, are not clauses.
+    fn profile_start(tokens_cursor: &Cursor<Vec<Token>>) -> Option<usize> {
+        let mut steps = 0;
+        let mut newline = false;
+
+        while let Some(token) = tokens_cursor.select_nexts(steps) {
+            match token.kind.as_str() {
+                "space" => steps += 1,
+                "newline" if !newline => {
+                    newline = true;
+                    steps += 1;
+                }
+                "at" => break,
+                _ => return None,
+            }
+        }
+
+        match tokens_cursor.select_nexts(steps + 1) {
+            Some(token) if token.kind == "keyword" && !DIRECTIVES.contains(&token.raw.as_str()) => {
+                Some(steps)
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_profile(tokens_cursor: &mut Cursor<Vec<Token>>) -> Result<Profile, SyntaxError> {
+        let span_start = tokens_cursor.assert_current_kind(["at"])?.span.start;
+        tokens_cursor.forward(1);
+
+        let name_token = tokens_cursor.assert_current_kind(["keyword"])?;
+        let name = name_token.raw.to_string();
+        let mut span_end = name_token.span.end;
+        tokens_cursor.forward(1);
+        tokens_cursor.forward_some_kind(["space"]);
+
+        let mut default_value = None;
+        let mut required = false;
+
+        match tokens_cursor.select_current() {
+            Some(token) if token.kind == "equal" => {
+                tokens_cursor.forward(1);
+                tokens_cursor.forward_some_kind(["space"]);
+                let default_value_expression = Self::parse_items_default_value(tokens_cursor)?;
+                span_end = default_value_expression.to_span().end;
+                default_value = Some(default_value_expression);
+            }
+            Some(token) if token.kind == "keyword" && token.raw == "required" => {
+                span_end = token.span.end;
+                required = true;
+                tokens_cursor.forward(1);
+            }
+            Some(token) => {
+                do yeet SyntaxError::new(
+                    format!(
+                    "Unexpected token {}, expected a default value or required for the profile {}",
+                    token.kind, name
+                ),
+                    token.span,
+                )
+            }
+            None => {
+                do yeet SyntaxError::new(
+                    format!(
+                        "Expected a default value or required for the profile {}",
+                        name
+                    ),
+                    Span {
+                        start: span_start,
+                        end: span_end,
+                    },
+                )
+            }
+        }
+
+        Ok(Profile {
+            span: Span {
+                start: span_start,
+                end: span_end,
+            },
+            name,
+            default_value: Box::new(default_value),
+            required,
+        })
+    }
+
     fn parse_items_default_value(
         tokens_cursor: &mut Cursor<Vec<Token>>,
     ) -> Result<Expression, SyntaxError> {
@@ -366,6 +1035,9 @@ impl AST {
             Some(token) if token.kind == "number" => {
                 Ok(Self::parse_items_default_value_number(tokens_cursor)?)
             }
+            Some(token) if token.kind == "open_bracket" => {
+                Ok(Self::parse_items_default_value_list(tokens_cursor)?)
+            }
             Some(token) => do yeet SyntaxError::new("Unexpected token", token.span.clone()),
             _ => {
                 do yeet SyntaxError::new(
@@ -403,6 +1075,45 @@ impl AST {
         return Ok(a);
     }
 
+    fn parse_items_default_value_list(
+        tokens_cursor: &mut Cursor<Vec<Token>>,
+    ) -> Result<Expression, SyntaxError> {
+        let span_start = tokens_cursor
+            .assert_current_kind(["open_bracket"])?
+            .span
+            .start;
+        let mut elements: Vec<Expression> = vec![];
+        tokens_cursor.forward(1);
+        tokens_cursor.forward_some_kind(["space", "newline"]);
+
+        while tokens_cursor
+            .assert_current_kind(["close_bracket"])
+            .is_err()
+        {
+            tokens_cursor.assert_current_kind(["string", "number"])?;
+            elements.push(Self::parse_items_default_value(tokens_cursor)?);
+            tokens_cursor.forward_some_kind(["space", "newline"]);
+
+            if tokens_cursor.assert_current_kind(["comma"]).is_ok() {
+                tokens_cursor.forward(1);
+                tokens_cursor.forward_some_kind(["space", "newline"]);
+            } else {
+                tokens_cursor.assert_current_kind(["close_bracket"])?;
+            }
+        }
+
+        let span_end = tokens_cursor.select_current().unwrap().span.end;
+        tokens_cursor.forward(1);
+
+        Ok(Expression::ListValue(ListValue {
+            span: Span {
+                start: span_start,
+                end: span_end,
+            },
+            elements,
+        }))
+    }
+
     fn parse_items_option_value(
         tokens_cursor: &mut Cursor<Vec<Token>>,
     ) -> Result<Expression, SyntaxError> {
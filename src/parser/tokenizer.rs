@@ -179,7 +179,10 @@ impl Tokenizer {
                 continue;
             }
 
-            if cursor.current_matches_range_char(&vec!['0'..='9']) {
+            if cursor.current_matches_range_char(&vec!['0'..='9'])
+                || (cursor.current_matches_char('-')
+                    && cursor.next_matches_range_char(&vec!['0'..='9']))
+            {
                 tokens.extend(Self::parse_number(cursor)?);
                 continue;
             }
@@ -199,6 +202,36 @@ impl Tokenizer {
                 continue;
             }
 
+            if cursor.current_matches_char('[') {
+                tokens.extend(Self::parse_single_char(cursor, '[', "open_bracket")?);
+                continue;
+            }
+
+            if cursor.current_matches_char(']') {
+                tokens.extend(Self::parse_single_char(cursor, ']', "close_bracket")?);
+                continue;
+            }
+
+            if cursor.current_matches_char(',') {
+                tokens.extend(Self::parse_single_char(cursor, ',', "comma")?);
+                continue;
+            }
+
+            if cursor.current_matches_char('@') {
+                tokens.extend(Self::parse_single_char(cursor, '@', "at")?);
+                continue;
+            }
+
+            if cursor.current_matches_char('{') {
+                tokens.extend(Self::parse_single_char(cursor, '{', "open_brace")?);
+                continue;
+            }
+
+            if cursor.current_matches_char('}') {
+                tokens.extend(Self::parse_single_char(cursor, '}', "close_brace")?);
+                continue;
+            }
+
             // dbg!(&cursor);
             // dbg!(&cursor.current_char());
             // dbg!(&tokens);
@@ -323,6 +356,22 @@ impl Tokenizer {
         Ok(vec![newline_token])
     }
 
+    /// Token of a single char, e.g. `[` or `,`
+    fn parse_single_char(
+        cursor: &mut Cursor,
+        ch: char,
+        kind: &str,
+    ) -> Result<Vec<Token>, SyntaxError> {
+        cursor.current_char_expected(ch);
+        let span_start = cursor.index;
+        cursor.forward(1);
+        let span = span::Span {
+            start: span_start,
+            end: cursor.index,
+        };
+        Ok(vec![Token::new(kind, cursor, span)])
+    }
+
     fn parse_string(cursor: &mut Cursor) -> Result<Vec<Token>, SyntaxError> {
         cursor.current_char_expected('"');
         cursor.forward(1);
@@ -371,8 +420,11 @@ impl Tokenizer {
     }
 
     fn parse_number(cursor: &mut Cursor) -> Result<Vec<Token>, SyntaxError> {
-        cursor.current_range_char_expected(&vec!['0'..='9']);
         let span_start = cursor.index;
+        if cursor.current_matches_char('-') {
+            cursor.forward(1);
+        }
+        cursor.current_range_char_expected(&vec!['0'..='9']);
         let mut decimal = false;
 
         while cursor.has_current() {
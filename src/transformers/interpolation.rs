@@ -0,0 +1,249 @@
+use super::super::envuse::evaluate_options::EvaluateOptions;
+use super::super::errors::parser_error::ParseError;
+use super::super::parser::ast::{DefaultValue, Document, Expression, Variable};
+use super::super::parser::span::Span;
+use super::parser::Parser;
+
+/// Replace the references `${NAME}` by the value of the variable `NAME`, e.g.
+/// `DATABASE_URL = "postgres://${DB_HOST}:${DB_PORT}/app"`. The value of a
+/// reference is the value from the environment or the default value of the
+/// variable, with its own references replaced. Use `$${` to write `${`.
+pub struct Interpolation<'a> {
+    options: &'a EvaluateOptions,
+    document: &'a Document,
+}
+
+/// A variable being interpolated, to find the cycles of the references
+struct Visit {
+    name: String,
+    key: String,
+    /// Start of the declaration, a cycle is reported from its first variable
+    start: usize,
+    /// Span of the reference that reached the variable
+    span: Span,
+}
+
+impl Visit {
+    fn new(options: &EvaluateOptions, variable: &Variable, span: Span) -> Self {
+        Self {
+            name: variable.env_name(),
+            key: Parser::env_key(options, variable),
+            start: variable.span.start,
+            span,
+        }
+    }
+}
+
+impl<'a> Interpolation<'a> {
+    pub fn new(options: &'a EvaluateOptions, document: &'a Document) -> Self {
+        Self { options, document }
+    }
+
+    /// Replace the references in a default value. The errors point to the
+    /// reference in the default value.
+    pub fn default_value(
+        &self,
+        variable: &Variable,
+        default_value: &DefaultValue,
+    ) -> Result<String, ParseError> {
+        let mut stack = vec![Visit::new(self.options, variable, variable.span)];
+        self.interpolate(
+            &default_value.value,
+            Some(default_value.span.start),
+            variable,
+            &mut stack,
+        )
+    }
+
+    /// Replace the references in a value from the environment if the option
+    /// `interpolate_envs` is enabled
+    pub fn env_value(&self, variable: &Variable, value: &str) -> Result<String, ParseError> {
+        if !self.options.interpolate_envs {
+            return Ok(value.to_string());
+        }
+
+        let mut stack = vec![Visit::new(self.options, variable, variable.span)];
+        self.interpolate(value, None, variable, &mut stack)
+    }
+
+    fn interpolate(
+        &self,
+        text: &str,
+        offset: Option<usize>,
+        variable: &Variable,
+        stack: &mut Vec<Visit>,
+    ) -> Result<String, ParseError> {
+        let chars: Vec<char> = text.chars().collect();
+        let span = |start: usize, end: usize| match offset {
+            Some(offset) => Span {
+                start: offset + start,
+                end: offset + end,
+            },
+            None => variable.span,
+        };
+
+        let mut result = String::new();
+        let mut index = 0;
+
+        while index < chars.len() {
+            if chars[index..].starts_with(&['$', '$', '{']) {
+                result.push_str("${");
+                index += 3;
+                continue;
+            }
+
+            if !chars[index..].starts_with(&['$', '{']) {
+                result.push(chars[index]);
+                index += 1;
+                continue;
+            }
+
+            let end = match chars[index..].iter().position(|c| *c == '}') {
+                Some(position) => index + position,
+                None => {
+                    do yeet ParseError::new(
+                        format!(
+                            "{}: the reference is not closed, expected }}",
+                            Parser::env_key(self.options, variable)
+                        ),
+                        span(index, chars.len()),
+                    )
+                }
+            };
+
+            let name: String = chars[index + 2..end].iter().collect();
+            let reference_span = span(index, end + 1);
+
+            let is_valid_name = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+            if !is_valid_name {
+                do yeet ParseError::new(
+                    format!(
+                        "{}: ${{{}}} is not a valid reference",
+                        Parser::env_key(self.options, variable),
+                        name
+                    ),
+                    reference_span,
+                )
+            }
+
+            result.push_str(&self.resolve(&name, reference_span, variable, stack)?);
+            index = end + 1;
+        }
+
+        Ok(result)
+    }
+
+    fn resolve(
+        &self,
+        name: &str,
+        reference_span: Span,
+        variable: &Variable,
+        stack: &mut Vec<Visit>,
+    ) -> Result<String, ParseError> {
+        // The names are relative to the section of the variable
+        let reference = match self.document.find_variable_from(&variable.path, name) {
+            Some(reference) => reference,
+            None => {
+                do yeet ParseError::new(
+                    format!(
+                        "{}: the variable {} is not declared",
+                        Parser::env_key(self.options, variable),
+                        name
+                    ),
+                    reference_span,
+                )
+            }
+        };
+
+        let reference_name = reference.env_name();
+
+        if let Some(index) = stack.iter().position(|visit| visit.name == reference_name) {
+            do yeet self.cycle_error(&stack[index..], variable, reference_span)
+        }
+
+        if Parser::is_sensitive(reference) && !Parser::is_sensitive(variable) {
+            do yeet ParseError::new(
+                format!(
+                    "{}: the variable {} is sensitive, it can only be interpolated in a sensitive variable",
+                    Parser::env_key(self.options, variable), name
+                ),
+                reference_span,
+            )
+        }
+
+        let value_env = self
+            .options
+            .envs
+            .get(&Parser::env_key(self.options, reference));
+
+        stack.push(Visit::new(self.options, reference, reference_span));
+
+        let value = match (value_env, Parser::default_value(self.options, reference)) {
+            (Some(Some(value_env)), _) if self.options.interpolate_envs => {
+                self.interpolate(value_env, None, reference, stack)
+            }
+            (Some(Some(value_env)), _) => Ok(value_env.to_string()),
+            (_, Some(Expression::DefaultValue(default_value))) => self.interpolate(
+                &default_value.value,
+                Some(default_value.span.start),
+                reference,
+                stack,
+            ),
+            (_, Some(Expression::ListValue(_))) => Err(ParseError::new(
+                format!(
+                    "{}: the variable {} is a list and cannot be interpolated",
+                    Parser::env_key(self.options, variable),
+                    name
+                ),
+                reference_span,
+            )),
+            _ => Err(ParseError::new(
+                format!(
+                    "{}: the variable {} has no value to interpolate",
+                    Parser::env_key(self.options, variable),
+                    name
+                ),
+                reference_span,
+            )),
+        };
+
+        stack.pop();
+
+        value
+    }
+    /// The cycle is reported from its first declared variable, on the
+    /// reference that closes it, so each variable of the cycle reports the
+    /// same error
+    fn cycle_error(
+        &self,
+        cycle: &[Visit],
+        variable: &Variable,
+        reference_span: Span,
+    ) -> ParseError {
+        let first = (0..cycle.len())
+            .min_by_key(|index| cycle[*index].start)
+            .unwrap_or(0);
+
+        let (key, span) = match first {
+            0 => (Parser::env_key(self.options, variable), reference_span),
+            index => (cycle[index - 1].key.clone(), cycle[index].span),
+        };
+
+        let names: Vec<&str> = cycle[first..]
+            .iter()
+            .chain(&cycle[..=first])
+            .map(|visit| visit.name.as_str())
+            .collect();
+
+        ParseError::new(
+            format!(
+                "{}: the interpolation has a cycle {}",
+                key,
+                names.join(" -> ")
+            ),
+            span,
+        )
+    }
+}
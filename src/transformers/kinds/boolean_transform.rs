@@ -1,12 +1,67 @@
-use crate::transformers::{trait_transformer::Transformer, value_types::ValueType};
+use crate::errors::transform_error::TransformError;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
+use crate::utils::did_you_mean::did_you_mean;
 
+const TRUE_WORDS: [&str; 4] = ["true", "on", "yes", "1"];
+const FALSE_WORDS: [&str; 4] = ["false", "off", "no", "0"];
+
+/// Read a boolean from the words `true`, `on`, `yes`, `1` and `false`, `off`,
+/// `no`, `0` (case-insensitive). The options `true` and `false` replace the
+/// words, e.g. `Boolean<true="enabled" false="disabled">`, and the option
+/// `lenient` reads any other word as `false`.
 pub struct BooleanTransform;
 
+fn words<'a>(options: &'a TypeOptions, key: &str, default_words: &[&'a str]) -> Vec<&'a str> {
+    match options.get(key) {
+        Some(option_value) => option_value.value.split_whitespace().collect(),
+        None => default_words.to_vec(),
+    }
+}
+
 impl Transformer for BooleanTransform {
-    fn parse(&self, type_input: String, input_value: String) -> ValueType {
-        ValueType::Boolean(match input_value.as_str() {
-            "on" | "true" | "1" => true,
-            _ => false,
-        })
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        let true_words = words(options, "true", &TRUE_WORDS);
+        let false_words = words(options, "false", &FALSE_WORDS);
+        let value = input_value.trim();
+
+        if true_words
+            .iter()
+            .any(|word| word.eq_ignore_ascii_case(value))
+        {
+            return Ok(ValueType::Boolean(true));
+        }
+
+        if false_words
+            .iter()
+            .any(|word| word.eq_ignore_ascii_case(value))
+        {
+            return Ok(ValueType::Boolean(false));
+        }
+
+        if options.contains_key("lenient") {
+            return Ok(ValueType::Boolean(false));
+        }
+
+        let mut message = format!(
+            "{:?} is not a valid boolean, expected one of {} or {}",
+            input_value,
+            true_words.join(", "),
+            false_words.join(", ")
+        );
+
+        let all_words = true_words.iter().chain(false_words.iter()).copied();
+
+        if let Some(suggestion) = did_you_mean(value, all_words) {
+            message.push_str(&format!(", did you mean {:?}?", suggestion));
+        }
+
+        Err(TransformError::new(message))
     }
 }
@@ -0,0 +1,138 @@
+use crate::errors::transform_error::TransformError;
+use crate::parser::ast::OptionValue;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
+
+const UNITS: [(&str, u64); 11] = [
+    ("B", 1),
+    ("kB", 1_000),
+    ("MB", 1_000_000),
+    ("GB", 1_000_000_000),
+    ("TB", 1_000_000_000_000),
+    ("PB", 1_000_000_000_000_000),
+    ("KiB", 1 << 10),
+    ("MiB", 1 << 20),
+    ("GiB", 1 << 30),
+    ("TiB", 1 << 40),
+    ("PiB", 1 << 50),
+];
+
+/// Read a size in bytes with SI (`kB`, `MB`, ..) or IEC (`KiB`, `MiB`, ..)
+/// suffixes, e.g. `512MB` or `1.5GiB`. A number without suffix is in bytes.
+/// The units without a `B` (`10M`), in bits (`10Mb`) or with an uppercase
+/// kilo (`10KB`) are rejected because are ambiguous.
+pub struct ByteSizeTransform;
+
+fn parse_byte_size(input_value: &str) -> Result<u64, String> {
+    let input_value = input_value.trim().replace('_', "");
+    let number_end = input_value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(input_value.len());
+    let (number, unit) = input_value.split_at(number_end);
+    let unit = unit.trim_start();
+
+    if number.is_empty() {
+        do yeet String::from("expected a number")
+    }
+
+    let multiplier = match UNITS.iter().find(|(name, _)| *name == unit) {
+        Some((_, multiplier)) => *multiplier,
+        None if unit.is_empty() => 1,
+        None => {
+            let upper_unit = unit.to_uppercase();
+            let prefix = upper_unit.trim_end_matches('B').trim_end_matches('I');
+            let known_prefix = ["K", "M", "G", "T", "P"].contains(&prefix);
+
+            if known_prefix {
+                let si_unit = match prefix {
+                    "K" => String::from("kB"),
+                    prefix => format!("{}B", prefix),
+                };
+
+                do yeet format!(
+                    "the unit {:?} is ambiguous, use {:?} (powers of 1000) or \"{}iB\" (powers of 1024)",
+                    unit, si_unit, prefix
+                )
+            }
+
+            do yeet format!("the unit {:?} is not valid", unit)
+        }
+    };
+
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+    if integer.is_empty() || fraction.contains('.') {
+        do yeet format!("{:?} is not a valid number", number)
+    }
+
+    // Decimal numbers are scaled to integers, e.g. `1.5` is read as 15 / 10
+    let scale = 10u128.checked_pow(fraction.len() as u32);
+    let scaled = format!("{}{}", integer, fraction).parse::<u128>().ok();
+
+    let (scale, scaled) = match scale.zip(scaled) {
+        Some((scale, scaled)) => match scaled.checked_mul(u128::from(multiplier)) {
+            Some(scaled) => (scale, scaled),
+            None => do yeet format!("the size is greater than {} bytes", u64::MAX),
+        },
+        None => do yeet format!("{:?} is not a valid number", number),
+    };
+
+    if scaled % scale != 0 {
+        do yeet String::from("the size is not a whole number of bytes")
+    }
+
+    u64::try_from(scaled / scale)
+        .map_err(|_| format!("the size is greater than {} bytes", u64::MAX))
+}
+
+fn parse_option(option_value: &OptionValue, key: &str) -> Result<u64, TransformError> {
+    parse_byte_size(&option_value.value).map_err(|message| {
+        TransformError::with_span(
+            format!("option {} has an invalid size, {}", key, message),
+            option_value.span,
+        )
+    })
+}
+
+impl Transformer for ByteSizeTransform {
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        let bytes = match parse_byte_size(&input_value) {
+            Ok(bytes) => bytes,
+            Err(message) => {
+                do yeet TransformError::new(format!(
+                    "{:?} is not a valid size, {}",
+                    input_value, message
+                ))
+            }
+        };
+
+        if let Some(min_option) = options.get("min") {
+            if bytes < parse_option(min_option, "min")? {
+                do yeet TransformError::with_span(
+                    format!("{} is less than the min {}", input_value, min_option.value),
+                    min_option.span,
+                )
+            }
+        }
+
+        if let Some(max_option) = options.get("max") {
+            if bytes > parse_option(max_option, "max")? {
+                do yeet TransformError::with_span(
+                    format!(
+                        "{} is greater than the max {}",
+                        input_value, max_option.value
+                    ),
+                    max_option.span,
+                )
+            }
+        }
+
+        Ok(ValueType::UInt(bytes))
+    }
+}
@@ -1,9 +1,17 @@
-use crate::transformers::{trait_transformer::Transformer, value_types::ValueType};
+use crate::errors::transform_error::TransformError;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
 
 pub struct CustomTransform;
 
 impl Transformer for CustomTransform {
-    fn parse(&self, type_input: String, input_value: String) -> ValueType {
-        ValueType::Custom(type_input, input_value)
+    fn parse(
+        &self,
+        type_input: String,
+        input_value: String,
+        _options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        Ok(ValueType::Custom(type_input, input_value))
     }
 }
@@ -0,0 +1,177 @@
+use crate::errors::transform_error::TransformError;
+use crate::parser::ast::OptionValue;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
+use std::time::Duration;
+
+const UNITS: [(&str, u64); 5] = [
+    ("ms", 1),
+    ("s", 1_000),
+    ("m", 60_000),
+    ("h", 3_600_000),
+    ("d", 86_400_000),
+];
+
+/// Read a duration with units, e.g. `30s`, `5m` or `1h30m`. A number without
+/// unit uses the option `unit` (`s` by default), e.g. `Duration<unit="ms">`.
+/// The options `min` and `max` are durations too.
+pub struct DurationTransform;
+
+fn unit_millis(unit: &str) -> Option<u64> {
+    UNITS
+        .iter()
+        .find(|(name, _)| *name == unit)
+        .map(|(_, millis)| *millis)
+}
+
+fn parse_duration(input_value: &str, default_unit: &str) -> Result<Duration, String> {
+    let input_value = input_value.trim().replace('_', "");
+
+    if input_value.is_empty() {
+        do yeet String::from("the value is empty")
+    }
+
+    if input_value.chars().all(|c| c.is_ascii_digit()) {
+        return parse_duration(&format!("{}{}", input_value, default_unit), default_unit);
+    }
+
+    let mut total: u64 = 0;
+    let mut rest = input_value.as_str();
+
+    while !rest.is_empty() {
+        let digits_end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit_end = rest[digits_end..]
+            .find(|c: char| c.is_ascii_digit())
+            .map_or(rest.len(), |index| digits_end + index);
+
+        let (amount, unit) = (&rest[..digits_end], &rest[digits_end..unit_end]);
+
+        if amount.is_empty() {
+            do yeet format!("expected a number before {:?}", unit)
+        }
+
+        let millis = match unit_millis(unit) {
+            Some(millis) => millis,
+            None if unit.is_empty() => do yeet format!("the unit is missing after {}", amount),
+            None => {
+                do yeet format!(
+                    "the unit {:?} is not valid, expected ms, s, m, h or d",
+                    unit
+                )
+            }
+        };
+
+        total = match amount
+            .parse::<u64>()
+            .ok()
+            .and_then(|amount| amount.checked_mul(millis))
+            .and_then(|amount| total.checked_add(amount))
+        {
+            Some(total) => total,
+            None => do yeet String::from("the duration is too long"),
+        };
+
+        rest = &rest[unit_end..];
+    }
+
+    Ok(Duration::from_millis(total))
+}
+
+/// Write a duration with the largest units, e.g. `1h30m`
+pub fn format_duration(duration: Duration) -> String {
+    let mut millis = duration.as_millis() as u64;
+
+    if millis == 0 {
+        return String::from("0s");
+    }
+
+    let mut formatted = String::new();
+
+    for (name, unit) in UNITS.iter().rev() {
+        if millis >= *unit {
+            formatted.push_str(&format!("{}{}", millis / unit, name));
+            millis %= unit;
+        }
+    }
+
+    formatted
+}
+
+fn parse_option(
+    option_value: &OptionValue,
+    key: &str,
+    default_unit: &str,
+) -> Result<Duration, TransformError> {
+    parse_duration(&option_value.value, default_unit).map_err(|message| {
+        TransformError::with_span(
+            format!("option {} has an invalid duration, {}", key, message),
+            option_value.span,
+        )
+    })
+}
+
+impl Transformer for DurationTransform {
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        let default_unit = match options.get("unit") {
+            Some(unit) if unit_millis(&unit.value).is_some() => unit.value.as_str(),
+            Some(unit) => {
+                do yeet TransformError::with_span(
+                    format!(
+                        "option unit has an invalid value {:?}, expected ms, s, m, h or d",
+                        unit.value
+                    ),
+                    unit.span,
+                )
+            }
+            None => "s",
+        };
+
+        let duration = match parse_duration(&input_value, default_unit) {
+            Ok(duration) => duration,
+            Err(message) => {
+                do yeet TransformError::new(format!(
+                    "{:?} is not a valid duration, {}",
+                    input_value, message
+                ))
+            }
+        };
+
+        if let Some(min_option) = options.get("min") {
+            let min = parse_option(min_option, "min", default_unit)?;
+            if duration < min {
+                do yeet TransformError::with_span(
+                    format!(
+                        "{} is less than the min {}",
+                        format_duration(duration),
+                        format_duration(min)
+                    ),
+                    min_option.span,
+                )
+            }
+        }
+
+        if let Some(max_option) = options.get("max") {
+            let max = parse_option(max_option, "max", default_unit)?;
+            if duration > max {
+                do yeet TransformError::with_span(
+                    format!(
+                        "{} is greater than the max {}",
+                        format_duration(duration),
+                        format_duration(max)
+                    ),
+                    max_option.span,
+                )
+            }
+        }
+
+        Ok(ValueType::Duration(duration))
+    }
+}
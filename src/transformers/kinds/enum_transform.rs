@@ -0,0 +1,46 @@
+use crate::errors::transform_error::TransformError;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
+use crate::utils::did_you_mean::did_you_mean;
+
+/// Accept only the values declared on the type options, e.g.
+/// `Enum<debug info>` or `Enum<values="us-east-1 us-west-2">`
+pub struct EnumTransform;
+
+impl Transformer for EnumTransform {
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        let mut allowed_values = options.flags();
+
+        if let Some(values) = options.get("values") {
+            allowed_values.extend(values.value.split_whitespace());
+        }
+
+        if allowed_values.is_empty() {
+            do yeet TransformError::new(
+                "the allowed values are not declared, e.g. Enum<debug info>",
+            )
+        }
+
+        if allowed_values.contains(&input_value.as_str()) {
+            return Ok(ValueType::String(input_value));
+        }
+
+        let mut message = format!(
+            "{:?} is not one of {}",
+            input_value,
+            allowed_values.join(", ")
+        );
+
+        if let Some(suggestion) = did_you_mean(&input_value, allowed_values) {
+            message.push_str(&format!(", did you mean {:?}?", suggestion));
+        }
+
+        Err(TransformError::new(message))
+    }
+}
@@ -0,0 +1,30 @@
+use crate::errors::transform_error::TransformError;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
+
+pub struct FloatTransform;
+
+impl Transformer for FloatTransform {
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        match input_value.trim().replace('_', "").parse::<f64>() {
+            Ok(number) if number.is_finite() => {
+                options.check_min_max(number)?;
+                Ok(ValueType::Float(number))
+            }
+            Ok(_) => Err(TransformError::new(format!(
+                "{} is out of range, expected a finite number",
+                input_value
+            ))),
+            Err(_) => Err(TransformError::new(format!(
+                "{:?} is not a valid float",
+                input_value
+            ))),
+        }
+    }
+}
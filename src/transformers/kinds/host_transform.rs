@@ -0,0 +1,108 @@
+use crate::errors::transform_error::TransformError;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
+use std::net::{IpAddr, Ipv6Addr};
+
+/// Read a host name (e.g. `api.example.com`) or an ip address, with an
+/// optional port, e.g. `db.internal:5432` or `[::1]:5432`. The host names are
+/// read in lowercase.
+pub struct HostTransform;
+
+/// Split the port of `host:port` or `[ip]:port`, an IPv6 address without
+/// brackets has no port
+fn split_port(host: &str) -> Result<(&str, Option<&str>), String> {
+    if let Some(rest) = host.strip_prefix('[') {
+        let (ip, rest) = match rest.split_once(']') {
+            Some(parts) => parts,
+            None => do yeet String::from("the [ is not closed"),
+        };
+
+        if ip.parse::<Ipv6Addr>().is_err() {
+            do yeet format!("{:?} is not a valid IPv6 address", ip)
+        }
+
+        return match rest {
+            "" => Ok((ip, None)),
+            rest => match rest.strip_prefix(':') {
+                Some(port) => Ok((ip, Some(port))),
+                None => Err(format!("expected a port after ]:, found {:?}", rest)),
+            },
+        };
+    }
+
+    match host.split_once(':') {
+        Some((name, port)) if !port.contains(':') => Ok((name, Some(port))),
+        _ => Ok((host, None)),
+    }
+}
+
+fn check_host_name(host: &str) -> Result<(), String> {
+    if host.is_empty() {
+        do yeet String::from("the host is empty")
+    }
+
+    if host.len() > 253 {
+        do yeet String::from("the host is longer than 253 characters")
+    }
+
+    for label in host.trim_end_matches('.').split('.') {
+        if label.is_empty() || label.len() > 63 {
+            do yeet format!(
+                "the label {:?} must have between 1 and 63 characters",
+                label
+            )
+        }
+
+        if label.starts_with('-') || label.ends_with('-') {
+            do yeet format!("the label {:?} cannot start or end with -", label)
+        }
+
+        if let Some(c) = label
+            .chars()
+            .find(|c| !c.is_ascii_alphanumeric() && *c != '-')
+        {
+            do yeet format!("the character {:?} is not allowed", c)
+        }
+    }
+
+    Ok(())
+}
+
+impl Transformer for HostTransform {
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        _options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        let host: Result<String, String> = try {
+            let (host, port) = split_port(input_value.trim())?;
+
+            let host = match host.parse::<IpAddr>() {
+                Ok(IpAddr::V6(ip)) if port.is_some() => format!("[{}]", ip),
+                Ok(ip) => ip.to_string(),
+                Err(_) => {
+                    check_host_name(host)?;
+                    host.to_lowercase()
+                }
+            };
+
+            match port {
+                Some(port) => match port.parse::<u16>() {
+                    Ok(number) if number > 0 => format!("{}:{}", host, number),
+                    _ => do yeet format!("the port {:?} is not a number between 1 and 65535", port),
+                },
+                None => host,
+            }
+        };
+
+        match host {
+            Ok(host) => Ok(ValueType::Host(host)),
+            Err(message) => Err(TransformError::new(format!(
+                "{:?} is not a valid host, {}",
+                input_value, message
+            ))),
+        }
+    }
+}
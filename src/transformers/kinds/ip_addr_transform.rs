@@ -0,0 +1,25 @@
+use crate::errors::transform_error::TransformError;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
+use std::net::IpAddr;
+
+/// Read an IPv4 or IPv6 address, e.g. `127.0.0.1` or `::1`
+pub struct IpAddrTransform;
+
+impl Transformer for IpAddrTransform {
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        _options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        match input_value.trim().parse::<IpAddr>() {
+            Ok(ip_addr) => Ok(ValueType::IpAddr(ip_addr)),
+            Err(_) => Err(TransformError::new(format!(
+                "{:?} is not a valid IPv4 or IPv6 address",
+                input_value
+            ))),
+        }
+    }
+}
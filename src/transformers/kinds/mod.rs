@@ -1,4 +1,14 @@
 pub mod boolean_transform;
+pub mod byte_size_transform;
 pub mod custom_transform;
+pub mod duration_transform;
+pub mod enum_transform;
+pub mod float_transform;
+pub mod host_transform;
+pub mod ip_addr_transform;
 pub mod number_transform;
+pub mod port_transform;
+pub mod socket_addr_transform;
 pub mod string_transform;
+pub mod uint_transform;
+pub mod url_transform;
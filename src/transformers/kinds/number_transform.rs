@@ -1,9 +1,40 @@
-use crate::transformers::{trait_transformer::Transformer, value_types::ValueType};
+use crate::errors::transform_error::TransformError;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
+use std::num::IntErrorKind;
 
 pub struct NumberTransform;
 
 impl Transformer for NumberTransform {
-    fn parse(&self, type_input: String, input_value: String) -> ValueType {
-        ValueType::Number(input_value.replace("_", "").parse::<u32>().unwrap())
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        match input_value.trim().replace('_', "").parse::<i64>() {
+            Ok(number) => {
+                options.check_min_max(number)?;
+                Ok(ValueType::Number(number))
+            }
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    IntErrorKind::PosOverflow | IntErrorKind::NegOverflow
+                ) =>
+            {
+                Err(TransformError::new(format!(
+                    "{} is out of range, expected a number between {} and {}",
+                    input_value,
+                    i64::MIN,
+                    i64::MAX
+                )))
+            }
+            Err(_) => Err(TransformError::new(format!(
+                "{:?} is not a valid number",
+                input_value
+            ))),
+        }
     }
 }
@@ -0,0 +1,27 @@
+use crate::errors::transform_error::TransformError;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
+
+/// Read a port between 1 and 65535
+pub struct PortTransform;
+
+impl Transformer for PortTransform {
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        match input_value.trim().parse::<u16>() {
+            Ok(port) if port > 0 => {
+                options.check_min_max(port)?;
+                Ok(ValueType::Port(port))
+            }
+            _ => Err(TransformError::new(format!(
+                "{:?} is not a valid port, expected a number between 1 and 65535",
+                input_value
+            ))),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::errors::transform_error::TransformError;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
+use std::net::SocketAddr;
+
+/// Read an address with a port between 1 and 65535, e.g. `0.0.0.0:8080` or
+/// `[::1]:8080`
+pub struct SocketAddrTransform;
+
+impl Transformer for SocketAddrTransform {
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        _options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        match input_value.trim().parse::<SocketAddr>() {
+            Ok(socket_addr) if socket_addr.port() > 0 => Ok(ValueType::SocketAddr(socket_addr)),
+            Ok(_) => Err(TransformError::new(format!(
+                "{:?} is not a valid socket address, the port must be between 1 and 65535",
+                input_value
+            ))),
+            Err(_) => Err(TransformError::new(format!(
+                "{:?} is not a valid socket address, expected ip:port, e.g. 0.0.0.0:8080 or [::1]:8080",
+                input_value
+            ))),
+        }
+    }
+}
@@ -1,9 +1,66 @@
-use crate::transformers::{trait_transformer::Transformer, value_types::ValueType};
+use crate::errors::transform_error::TransformError;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
+use regex::Regex;
 
 pub struct StringTransform;
 
 impl Transformer for StringTransform {
-    fn parse(&self, type_input: String, input_value: String) -> ValueType {
-        ValueType::String(input_value)
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        let length = input_value.chars().count();
+
+        if let Some(min_length) = options.parse::<usize>("minLength")? {
+            if length < min_length {
+                do yeet TransformError::with_span(
+                    format!(
+                        "{:?} is shorter than the minLength {}",
+                        input_value, min_length
+                    ),
+                    options.get("minLength").unwrap().span,
+                )
+            }
+        }
+
+        if let Some(max_length) = options.parse::<usize>("maxLength")? {
+            if length > max_length {
+                do yeet TransformError::with_span(
+                    format!(
+                        "{:?} is longer than the maxLength {}",
+                        input_value, max_length
+                    ),
+                    options.get("maxLength").unwrap().span,
+                )
+            }
+        }
+
+        if let Some(pattern) = options.get("pattern") {
+            let regex = match Regex::new(&pattern.value) {
+                Ok(regex) => regex,
+                Err(_) => {
+                    do yeet TransformError::with_span(
+                        format!("option pattern {:?} is not a valid regex", pattern.value),
+                        pattern.span,
+                    )
+                }
+            };
+
+            if !regex.is_match(&input_value) {
+                do yeet TransformError::with_span(
+                    format!(
+                        "{:?} does not match the pattern {:?}",
+                        input_value, pattern.value
+                    ),
+                    pattern.span,
+                )
+            }
+        }
+
+        Ok(ValueType::String(input_value))
     }
 }
@@ -0,0 +1,39 @@
+use crate::errors::transform_error::TransformError;
+use crate::transformers::{
+    trait_transformer::Transformer, type_options::TypeOptions, value_types::ValueType,
+};
+use std::num::IntErrorKind;
+
+pub struct UIntTransform;
+
+impl Transformer for UIntTransform {
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        let number = input_value.trim().replace('_', "");
+
+        match number.parse::<u64>() {
+            Ok(number) => {
+                options.check_min_max(number)?;
+                Ok(ValueType::UInt(number))
+            }
+            Err(error)
+                if *error.kind() == IntErrorKind::PosOverflow
+                    || number.starts_with('-') && number.parse::<i64>().is_ok() =>
+            {
+                Err(TransformError::new(format!(
+                    "{} is out of range, expected a number between 0 and {}",
+                    input_value,
+                    u64::MAX
+                )))
+            }
+            Err(_) => Err(TransformError::new(format!(
+                "{:?} is not a valid unsigned number",
+                input_value
+            ))),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::errors::transform_error::TransformError;
+use crate::transformers::{
+    trait_transformer::Transformer,
+    type_options::TypeOptions,
+    value_types::{UrlValue, ValueType},
+};
+use crate::utils::did_you_mean::did_you_mean;
+
+/// Read an url with the form `scheme://[user[:password]@]host[:port][/path][?query][#fragment]`.
+/// The option `schemes` limits the schemes allowed, e.g. `Url<schemes="postgres redis">`.
+pub struct UrlTransform;
+
+fn parse_url(input_value: &str) -> Result<UrlValue, String> {
+    let (scheme, rest) = match input_value.split_once("://") {
+        Some(parts) => parts,
+        None => do yeet String::from("the scheme is missing, e.g. https://"),
+    };
+
+    let valid_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+
+    if !valid_scheme {
+        do yeet format!("the scheme {:?} is not valid", scheme)
+    }
+
+    let (rest, fragment) = match rest.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment.to_string())),
+        None => (rest, None),
+    };
+
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query.to_string())),
+        None => (rest, None),
+    };
+
+    let (authority, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, ""),
+    };
+
+    let (user_info, host_port) = match authority.rsplit_once('@') {
+        Some((user_info, host_port)) => (Some(user_info), host_port),
+        None => (None, authority),
+    };
+
+    let (username, password) = match user_info {
+        Some(user_info) => match user_info.split_once(':') {
+            Some((username, password)) => (Some(username.to_string()), Some(password.to_string())),
+            None => (Some(user_info.to_string()), None),
+        },
+        None => (None, None),
+    };
+
+    // The IPv6 addresses are written between brackets, e.g. `[::1]:8080`
+    let port_separator = match host_port.rfind(']') {
+        Some(index) => host_port[index..].find(':').map(|i| index + i),
+        None => host_port.rfind(':'),
+    };
+
+    let (host, port) = match port_separator {
+        Some(index) => {
+            let port = &host_port[index + 1..];
+            match port.parse::<u16>() {
+                Ok(port) => (&host_port[..index], Some(port)),
+                Err(_) => do yeet format!("the port {:?} is not valid", port),
+            }
+        }
+        None => (host_port, None),
+    };
+
+    if host.is_empty() {
+        do yeet String::from("the host is missing")
+    }
+
+    if host.contains(char::is_whitespace) {
+        do yeet format!("the host {:?} is not valid", host)
+    }
+
+    Ok(UrlValue {
+        scheme: scheme.to_lowercase(),
+        username,
+        password,
+        host: host.to_string(),
+        port,
+        path: path.to_string(),
+        query,
+        fragment,
+    })
+}
+
+impl Transformer for UrlTransform {
+    fn parse(
+        &self,
+        _type_input: String,
+        input_value: String,
+        options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        let url = match parse_url(&input_value) {
+            Ok(url) => url,
+            Err(message) => {
+                do yeet TransformError::new(format!(
+                    "{:?} is not a valid url, {}",
+                    input_value, message
+                ))
+            }
+        };
+
+        if let Some(schemes) = options.get("schemes") {
+            let schemes: Vec<&str> = schemes.value.split_whitespace().collect();
+
+            if !schemes.contains(&url.scheme.as_str()) {
+                let mut message = format!(
+                    "the scheme {} is not allowed, expected one of {}",
+                    url.scheme,
+                    schemes.join(", ")
+                );
+
+                if let Some(suggestion) = did_you_mean(&url.scheme, schemes) {
+                    message.push_str(&format!(", did you mean {:?}?", suggestion));
+                }
+
+                do yeet TransformError::new(message)
+            }
+        }
+
+        Ok(ValueType::Url(url))
+    }
+}
@@ -1,5 +1,9 @@
+pub mod interpolation;
 pub mod kinds;
 pub mod parser;
+pub mod secret_value;
 pub mod trait_transformer;
 pub mod transformer_list;
+pub mod type_options;
+pub mod value_deserializer;
 pub mod value_types;
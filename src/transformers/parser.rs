@@ -1,17 +1,268 @@
 use super::super::errors::parser_error::ParseError;
 
-use super::super::parser::ast::Variable;
-use super::transformer_list::TransformerList;
+use super::super::envuse::evaluate_options::EvaluateOptions;
+use super::super::parser::ast::{Document, Expression, Profile, Variable};
+use super::super::parser::span::Span;
+use super::interpolation::Interpolation;
+use super::kinds::custom_transform::CustomTransform;
+use super::secret_value::SecretValue;
+use super::trait_transformer::Transformer;
+use super::type_options::TypeOptions;
 use super::value_types::ValueType;
-use std::collections::BTreeMap;
+
+/// Value to transform and the span used to report its errors
+struct RawValue {
+    value: String,
+    span: Span,
+}
 
 pub struct Parser;
 
 impl Parser {
     pub fn to_parse_variable(
-        transformer_list: &TransformerList,
+        options: &EvaluateOptions,
+        document: &Document,
+        variable: &Variable,
+    ) -> Result<ValueType, ParseError> {
+        let interpolation = Interpolation::new(options, document);
+
+        if !Self::is_sensitive(variable) {
+            return Self::to_parse_value(options, &interpolation, variable);
+        }
+
+        match Self::to_parse_value(options, &interpolation, variable) {
+            Ok(ValueType::Null) => Ok(ValueType::Null),
+            Ok(value) => Ok(ValueType::Secret(SecretValue::new(value))),
+            Err(error) => {
+                let raw_values = Self::sensitive_values(options, &interpolation, variable);
+                let raw_values: Vec<&str> = raw_values.iter().map(String::as_str).collect();
+
+                Err(ParseError::new(
+                    redact_message(error.message, &raw_values),
+                    error.span,
+                ))
+            }
+        }
+    }
+
+    /// Values that can be shown by the errors of a sensitive variable
+    fn sensitive_values(
+        options: &EvaluateOptions,
+        interpolation: &Interpolation,
+        variable: &Variable,
+    ) -> Vec<String> {
+        let mut raw_values: Vec<String> = vec![];
+
+        match options.envs.get(&Self::env_key(options, variable)) {
+            Some(Some(value_env)) => {
+                raw_values.push(value_env.to_string());
+                raw_values.extend(interpolation.env_value(variable, value_env).ok());
+            }
+            _ => match Self::default_value(options, variable) {
+                Some(Expression::DefaultValue(default_value)) => {
+                    raw_values.push(default_value.value.to_string());
+                    raw_values.extend(interpolation.default_value(variable, default_value).ok());
+                }
+                Some(Expression::ListValue(list_value)) => {
+                    for default_value in list_value
+                        .elements
+                        .iter()
+                        .filter_map(|expression| expression.as_default_value())
+                    {
+                        raw_values.push(default_value.value.to_string());
+                        raw_values
+                            .extend(interpolation.default_value(variable, default_value).ok());
+                    }
+                }
+                _ => {}
+            },
+        }
+
+        if Self::is_list(variable) {
+            let type_options = TypeOptions::from_variable(variable);
+            let separator = type_options
+                .get("separator")
+                .map_or(",", |separator| separator.value.as_str());
+
+            if !separator.is_empty() {
+                let elements: Vec<String> = raw_values
+                    .iter()
+                    .flat_map(|raw_value| raw_value.split(separator))
+                    .map(|element| element.trim().to_string())
+                    .collect();
+                raw_values.extend(elements);
+            }
+        }
+
+        raw_values
+    }
+
+    /// Default value of the variable in the profile of the evaluation. A
+    /// profile can replace the default value or require the variable.
+    pub fn default_value<'a>(
+        options: &EvaluateOptions,
+        variable: &'a Variable,
+    ) -> Option<&'a Expression> {
+        match Self::active_profile(options, variable) {
+            Some(profile) if profile.required => None,
+            Some(profile) if profile.default_value.is_some() => {
+                profile.default_value.as_ref().as_ref()
+            }
+            _ => variable.default_value.as_ref().as_ref(),
+        }
+    }
+
+    /// A nullable variable is required in the profiles with `@name required`
+    pub fn is_nullable(options: &EvaluateOptions, variable: &Variable) -> bool {
+        variable.nullable
+            && !Self::active_profile(options, variable).map_or(false, |profile| profile.required)
+    }
+
+    fn active_profile<'a>(
+        options: &EvaluateOptions,
+        variable: &'a Variable,
+    ) -> Option<&'a Profile> {
+        options
+            .profile
+            .as_ref()
+            .and_then(|profile| variable.profile(profile))
+    }
+
+    fn null_message(options: &EvaluateOptions, variable: &Variable, env_key: &str) -> String {
+        match Self::active_profile(options, variable) {
+            Some(profile) if profile.required => {
+                format!("{} is required in the profile {}", env_key, profile.name)
+            }
+            _ => format!("{} value cannot be null", env_key),
+        }
+    }
+
+    /// A variable is sensitive if its type is `Secret` (or a list of
+    /// `Secret`) or has the option `sensitive`, e.g. `Url<sensitive>`
+    pub fn is_sensitive(variable: &Variable) -> bool {
+        let is_secret_type = variable
+            .variable_type
+            .as_ref()
+            .map_or(false, |variable_type| {
+                variable_type.eq_ignore_ascii_case("secret")
+            });
+        let has_sensitive_option = variable
+            .options_variable_type
+            .as_ref()
+            .map_or(false, |options| options.contains_key("sensitive"));
+        let is_list_of_secrets = Self::is_list(variable)
+            && variable
+                .options_variable_type
+                .as_ref()
+                .map_or(false, |options| {
+                    options.keys().any(|key| key.eq_ignore_ascii_case("secret"))
+                });
+
+        is_secret_type || has_sensitive_option || is_list_of_secrets
+    }
+
+    fn is_list(variable: &Variable) -> bool {
+        variable
+            .variable_type
+            .as_ref()
+            .map_or(false, |variable_type| {
+                variable_type.eq_ignore_ascii_case("list")
+            })
+    }
+
+    /// Name of the variable in the environment. Is the first name of
+    /// [`Parser::env_names`] with a value in the environment, otherwise the
+    /// name of the variable.
+    pub fn env_key(options: &EvaluateOptions, variable: &Variable) -> String {
+        let env_names = Self::env_names(options, variable);
+
+        env_names
+            .iter()
+            .find_map(|env_name| Self::find_env_key(options, env_name))
+            .unwrap_or_else(|| env_names[0].to_string())
+    }
+
+    /// Names to find the variable in the environment, in order: the name of
+    /// the variable and its aliases (option `alias="OLD_NAME, OTHER_NAME"`),
+    /// with the prefix
+    pub fn env_names(options: &EvaluateOptions, variable: &Variable) -> Vec<String> {
+        let prefix = options.prefix.as_deref().unwrap_or_default();
+
+        [variable.env_name()]
+            .into_iter()
+            .chain(Self::aliases(variable).into_iter().map(|(alias, _)| alias))
+            .map(|env_name| format!("{}{}", prefix, env_name))
+            .collect()
+    }
+
+    /// Aliases of the variable with the span of the option
+    pub fn aliases(variable: &Variable) -> Vec<(String, Span)> {
+        let type_options = TypeOptions::from_variable(variable);
+        let option_value = match type_options.get("alias") {
+            Some(option_value) => option_value,
+            None => return vec![],
+        };
+
+        let mut aliases = vec![];
+        let mut offset = option_value.span.start;
+
+        for part in option_value.value.split(',') {
+            let alias = part.trim();
+            let start = offset + part.chars().take_while(|c| c.is_whitespace()).count();
+
+            if !alias.is_empty() {
+                let span = Span {
+                    start,
+                    end: start + alias.chars().count(),
+                };
+                aliases.push((alias.to_string(), span));
+            }
+
+            offset += part.chars().count() + 1;
+        }
+
+        aliases
+    }
+
+    /// Name found in the environment and span of the alias, if the variable
+    /// is read from an alias instead of its name
+    pub fn matched_alias(options: &EvaluateOptions, variable: &Variable) -> Option<(String, Span)> {
+        let prefix = options.prefix.as_deref().unwrap_or_default();
+        let env_name = format!("{}{}", prefix, variable.env_name());
+
+        if Self::find_env_key(options, &env_name).is_some() {
+            return None;
+        }
+
+        Self::aliases(variable)
+            .into_iter()
+            .find_map(|(alias, span)| {
+                Self::find_env_key(options, &format!("{}{}", prefix, alias)).map(|key| (key, span))
+            })
+    }
+
+    /// Name with a value in the environment. With the option
+    /// `case_insensitive` the case of the name is ignored.
+    fn find_env_key(options: &EvaluateOptions, env_name: &str) -> Option<String> {
+        if let Some(Some(_)) = options.envs.get(env_name) {
+            return Some(env_name.to_string());
+        }
+
+        if !options.case_insensitive {
+            return None;
+        }
+
+        options
+            .envs
+            .iter()
+            .find(|(key, value)| value.is_some() && key.eq_ignore_ascii_case(env_name))
+            .map(|(key, _)| key.to_string())
+    }
+
+    fn to_parse_value(
+        options: &EvaluateOptions,
+        interpolation: &Interpolation,
         variable: &Variable,
-        envs: &BTreeMap<String, Option<String>>,
     ) -> Result<ValueType, ParseError> {
         let default_type = String::from("String");
         let transform_type = variable
@@ -20,39 +271,191 @@ impl Parser {
             .unwrap_or(default_type)
             .to_lowercase();
 
-        let transformer = match transformer_list.get(&transform_type) {
-            Some(transformer) => transformer,
-            _ => {
-                do yeet ParseError::new(
-                    format!("Type {} is not valid type", transform_type),
-                    variable.span,
-                )
-            }
-        };
+        let env_key = Self::env_key(options, variable);
+        let value_env = options.envs.get(&env_key).unwrap_or(&None);
 
-        let value_env = envs.get(&variable.name).unwrap_or(&None);
+        let default_value = Self::default_value(options, variable);
 
-        if variable.nullable && value_env.is_none() && variable.default_value.is_none() {
+        if Self::is_nullable(options, variable) && value_env.is_none() && default_value.is_none() {
             return Ok(ValueType::Null);
         }
 
-        let value_to_transform = if let Some(value_env) = value_env {
-            value_env.to_string()
-        } else if let Some(expression) = variable.default_value.as_ref() {
-            if let Some(default_value) = expression.as_default_value() {
-                default_value.value.to_string()
-            } else {
-                do yeet ParseError::new(format!("Expression cannot found error"), variable.span)
+        let type_options = TypeOptions::from_variable(variable);
+
+        if Self::is_list(variable) {
+            return Self::to_parse_list(options, interpolation, variable, value_env, type_options);
+        }
+
+        let transformer = Self::get_transformer(options, &transform_type, variable.span)?;
+
+        let raw_value = if let Some(value_env) = value_env {
+            RawValue {
+                value: interpolation.env_value(variable, value_env)?,
+                span: variable.span,
+            }
+        } else if let Some(expression) = default_value {
+            match expression {
+                Expression::DefaultValue(default_value) => RawValue {
+                    value: interpolation.default_value(variable, default_value)?,
+                    span: default_value.span,
+                },
+                Expression::ListValue(list_value) => {
+                    do yeet ParseError::new(
+                        format!("{}: a list default value requires the List type", env_key),
+                        list_value.span,
+                    )
+                }
+                _ => do yeet ParseError::new("Expression cannot found error", variable.span),
             }
         } else {
             do yeet ParseError::new(
-                format!("{} value cannot be null", &variable.name),
+                Self::null_message(options, variable, &env_key),
                 variable.span,
             )
         };
 
-        Ok(transformer
-            .as_ref()
-            .parse(transform_type, value_to_transform))
+        match transformer.parse(transform_type, raw_value.value, &type_options) {
+            Ok(value) => Ok(value),
+            Err(error) => Err(ParseError::new(
+                format!("{}: {}", env_key, error.message),
+                error.span.unwrap_or(raw_value.span),
+            )),
+        }
+    }
+
+    /// Type of the elements of a list, e.g. `Number` in `List<Number separator=";">`.
+    /// Is the first option without value known as type, otherwise the first
+    /// option without value.
+    pub fn list_element_type(options: &EvaluateOptions, type_options: &TypeOptions) -> String {
+        let flags = type_options.flags();
+
+        flags
+            .iter()
+            .find(|flag| options.transformers.contains_key(&flag.to_lowercase()))
+            .or_else(|| flags.first())
+            .map(|flag| flag.to_string())
+            .unwrap_or_else(|| String::from("String"))
+    }
+
+    fn get_transformer<'a>(
+        options: &'a EvaluateOptions,
+        transform_type: &str,
+        span: Span,
+    ) -> Result<&'a dyn Transformer, ParseError> {
+        match options.transformers.get(transform_type) {
+            Some(transformer) => Ok(transformer),
+            None if !options.strict => Ok(&CustomTransform),
+            _ => Err(ParseError::new(
+                format!("Type {} is not valid type", transform_type),
+                span,
+            )),
+        }
     }
+
+    fn to_parse_list(
+        options: &EvaluateOptions,
+        interpolation: &Interpolation,
+        variable: &Variable,
+        value_env: &Option<String>,
+        mut type_options: TypeOptions,
+    ) -> Result<ValueType, ParseError> {
+        let env_key = Self::env_key(options, variable);
+        let element_type = Self::list_element_type(options, &type_options);
+        let transform_type = element_type.to_lowercase();
+        let transformer = Self::get_transformer(options, &transform_type, variable.span)?;
+
+        let separator = match type_options.get("separator") {
+            Some(separator) if separator.value.is_empty() => {
+                do yeet ParseError::new(
+                    format!("{}: option separator cannot be empty", env_key),
+                    separator.span,
+                )
+            }
+            Some(separator) => separator.value.to_string(),
+            None => String::from(","),
+        };
+
+        type_options.remove(&element_type);
+        type_options.remove("separator");
+
+        let split = |value: &str, span: Span| -> Vec<RawValue> {
+            if value.trim().is_empty() {
+                return vec![];
+            }
+            value
+                .split(separator.as_str())
+                .map(|element| RawValue {
+                    value: element.trim().to_string(),
+                    span,
+                })
+                .collect()
+        };
+
+        let raw_values = if let Some(value_env) = value_env {
+            split(
+                &interpolation.env_value(variable, value_env)?,
+                variable.span,
+            )
+        } else {
+            match Self::default_value(options, variable) {
+                Some(Expression::ListValue(list_value)) => {
+                    let mut raw_values = vec![];
+                    for default_value in list_value
+                        .elements
+                        .iter()
+                        .filter_map(|expression| expression.as_default_value())
+                    {
+                        raw_values.push(RawValue {
+                            value: interpolation.default_value(variable, default_value)?,
+                            span: default_value.span,
+                        });
+                    }
+                    raw_values
+                }
+                Some(Expression::DefaultValue(default_value)) => split(
+                    &interpolation.default_value(variable, default_value)?,
+                    default_value.span,
+                ),
+                Some(_) => do yeet ParseError::new("Expression cannot found error", variable.span),
+                None => {
+                    do yeet ParseError::new(
+                        Self::null_message(options, variable, &env_key),
+                        variable.span,
+                    )
+                }
+            }
+        };
+
+        let mut values = vec![];
+
+        for (index, raw_value) in raw_values.into_iter().enumerate() {
+            match transformer.parse(transform_type.clone(), raw_value.value, &type_options) {
+                Ok(value) => values.push(value),
+                Err(error) => {
+                    do yeet ParseError::new(
+                        format!("{}[{}]: {}", env_key, index, error.message),
+                        error.span.unwrap_or(raw_value.span),
+                    )
+                }
+            }
+        }
+
+        Ok(ValueType::List(values))
+    }
+}
+
+/// Hide the raw values of a sensitive variable in an error message
+fn redact_message(message: String, raw_values: &[&str]) -> String {
+    let mut raw_values = raw_values.to_vec();
+    // The longer values first, a value can contain another one
+    raw_values.sort_by_key(|raw_value| std::cmp::Reverse(raw_value.len()));
+
+    raw_values
+        .iter()
+        .filter(|raw_value| !raw_value.is_empty())
+        .fold(message, |message, raw_value| {
+            message
+                .replace(&format!("{:?}", raw_value), "[REDACTED]")
+                .replace(raw_value, "[REDACTED]")
+        })
 }
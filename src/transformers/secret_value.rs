@@ -0,0 +1,91 @@
+use super::value_types::ValueType;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use zeroize::Zeroize;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Container of the value of a secret variable. The value is hidden in the
+/// `Debug`, `Display` and serde outputs, and zeroized on drop.
+pub struct SecretValue(Box<ValueType>);
+
+impl SecretValue {
+    pub fn new(value: ValueType) -> Self {
+        Self(Box::new(value))
+    }
+
+    pub fn expose(&self) -> &ValueType {
+        &self.0
+    }
+
+    /// Take the value out of the container. The caller is in charge of the
+    /// value from here.
+    pub fn into_inner(mut self) -> ValueType {
+        std::mem::replace(&mut self.0, ValueType::Null)
+    }
+}
+
+impl Drop for SecretValue {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for SecretValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl fmt::Display for SecretValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl Serialize for SecretValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(REDACTED)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ValueType::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl Zeroize for ValueType {
+    fn zeroize(&mut self) {
+        match self {
+            ValueType::Boolean(value) => value.zeroize(),
+            ValueType::Number(value) => value.zeroize(),
+            ValueType::UInt(value) => value.zeroize(),
+            ValueType::Float(value) => value.zeroize(),
+            ValueType::Port(value) => value.zeroize(),
+            ValueType::String(value) | ValueType::Host(value) => value.zeroize(),
+            ValueType::Custom(type_input, value) => {
+                type_input.zeroize();
+                value.zeroize();
+            }
+            ValueType::List(values) => values.zeroize(),
+            ValueType::Map(values) => values.values_mut().for_each(Zeroize::zeroize),
+            ValueType::Url(url) => {
+                url.scheme.zeroize();
+                url.username.zeroize();
+                url.password.zeroize();
+                url.host.zeroize();
+                url.path.zeroize();
+                url.query.zeroize();
+                url.fragment.zeroize();
+            }
+            ValueType::Secret(secret) => secret.0.zeroize(),
+            ValueType::Null
+            | ValueType::Duration(_)
+            | ValueType::IpAddr(_)
+            | ValueType::SocketAddr(_) => {}
+        }
+
+        *self = ValueType::Null;
+    }
+}
@@ -1,5 +1,26 @@
+use super::super::errors::transform_error::TransformError;
+use super::type_options::TypeOptions;
 use super::value_types::ValueType;
 
 pub trait Transformer {
-    fn parse(&self, type_input: String, input_value: String) -> ValueType;
+    fn parse(
+        &self,
+        type_input: String,
+        input_value: String,
+        options: &TypeOptions,
+    ) -> Result<ValueType, TransformError>;
+}
+
+impl<F> Transformer for F
+where
+    F: Fn(String, String, &TypeOptions) -> Result<ValueType, TransformError>,
+{
+    fn parse(
+        &self,
+        type_input: String,
+        input_value: String,
+        options: &TypeOptions,
+    ) -> Result<ValueType, TransformError> {
+        self(type_input, input_value, options)
+    }
 }
@@ -1,10 +1,17 @@
+use super::super::errors::transform_error::TransformError;
 use super::trait_transformer::Transformer;
+use super::type_options::TypeOptions;
+use super::value_types::ValueType;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 type TransformerType = Box<dyn Transformer>;
 
+/// Transformers by type name. The names are case-insensitive. A clone
+/// shares the transformers.
+#[derive(Clone)]
 pub struct TransformerList {
-    transformers: HashMap<String, TransformerType>,
+    transformers: HashMap<String, Rc<dyn Transformer>>,
 }
 
 impl TransformerList {
@@ -15,11 +22,40 @@ impl TransformerList {
     }
 
     pub fn insert<T: ToString>(&mut self, key: T, transformer: TransformerType) {
-        self.transformers.insert(key.to_string(), transformer);
+        self.transformers
+            .insert(key.to_string().to_lowercase(), Rc::from(transformer));
     }
 
-    pub fn get<T: ToString>(&self, transform_type: T) -> Option<&Box<dyn Transformer>> {
-        self.transformers.get(&transform_type.to_string())
+    /// Register a closure as transformer, e.g.
+    ///
+    /// ```ignore
+    /// transformers.insert_fn("KafkaTopic", |_type_input, value, _options| {
+    ///     Ok(ValueType::String(value))
+    /// });
+    /// ```
+    pub fn insert_fn<T, F>(&mut self, key: T, transformer: F)
+    where
+        T: ToString,
+        F: Fn(String, String, &TypeOptions) -> Result<ValueType, TransformError> + 'static,
+    {
+        self.insert(key, Box::new(transformer));
+    }
+
+    pub fn get<T: ToString>(&self, transform_type: T) -> Option<&dyn Transformer> {
+        self.transformers
+            .get(&transform_type.to_string().to_lowercase())
+            .map(|transformer| transformer.as_ref())
+    }
+
+    pub fn contains_key<T: ToString>(&self, transform_type: T) -> bool {
+        self.transformers
+            .contains_key(&transform_type.to_string().to_lowercase())
+    }
+
+    /// Move the transformers of `other` into this list, replacing the
+    /// transformers with the same name
+    pub fn extend(&mut self, other: TransformerList) {
+        self.transformers.extend(other.transformers);
     }
 }
 
@@ -0,0 +1,127 @@
+use super::super::errors::transform_error::TransformError;
+use super::super::parser::ast::{OptionValue, Variable};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Options read for any type, they are never values of the type, e.g. `sensitive` in
+/// `Enum<debug info sensitive>`
+pub const RESERVED_OPTIONS: [&str; 2] = ["alias", "sensitive"];
+
+/// Options declared next to the type of a variable, e.g. `Number<min=1 max=65535>`
+#[derive(Debug, Clone, Default)]
+pub struct TypeOptions {
+    options: BTreeMap<String, Option<OptionValue>>,
+    order: Vec<String>,
+}
+
+impl TypeOptions {
+    pub fn new() -> Self {
+        Self {
+            options: BTreeMap::new(),
+            order: vec![],
+        }
+    }
+
+    pub fn from_variable(variable: &Variable) -> Self {
+        let mut type_options = Self::new();
+
+        if let Some(options) = &variable.options_variable_type {
+            let declared = variable
+                .options_order
+                .iter()
+                .filter_map(|key| options.get_key_value(key));
+            let undeclared = options
+                .iter()
+                .filter(|(key, _)| !variable.options_order.contains(key));
+
+            for (key, expression) in declared.chain(undeclared) {
+                let option_value = expression
+                    .as_ref()
+                    .and_then(|expression| expression.as_option_value())
+                    .cloned();
+                type_options.insert(key, option_value);
+            }
+        }
+
+        type_options
+    }
+
+    pub fn insert<T: ToString>(&mut self, key: T, value: Option<OptionValue>) {
+        let key = key.to_string();
+        if !self.order.contains(&key) {
+            self.order.push(key.clone());
+        }
+        self.options.insert(key, value);
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Option<OptionValue>> {
+        self.order.retain(|declared| declared != key);
+        self.options.remove(key)
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.options.contains_key(key)
+    }
+
+    /// Value of an option declared as `key=value`
+    pub fn get(&self, key: &str) -> Option<&OptionValue> {
+        self.options.get(key).and_then(|value| value.as_ref())
+    }
+
+    /// Options declared without value in the declared order, e.g. `debug` and `info` in
+    /// `Enum<debug info>`. The reserved options like `sensitive` are excluded.
+    pub fn flags(&self) -> Vec<&str> {
+        self.order
+            .iter()
+            .filter(|key| matches!(self.options.get(*key), Some(None)))
+            .map(|key| key.as_str())
+            .filter(|key| !RESERVED_OPTIONS.contains(key))
+            .collect()
+    }
+
+    /// Parse the value of an option. The error points to the span of the option value.
+    pub fn parse<T: FromStr>(&self, key: &str) -> Result<Option<T>, TransformError> {
+        let option_value = match self.get(key) {
+            Some(option_value) => option_value,
+            None => return Ok(None),
+        };
+
+        match option_value.value.replace('_', "").parse::<T>() {
+            Ok(value) => Ok(Some(value)),
+            Err(_) => Err(TransformError::with_span(
+                format!(
+                    "option {} has an invalid value {:?}",
+                    key, option_value.value
+                ),
+                option_value.span,
+            )),
+        }
+    }
+
+    /// Check the value is between the options `min` and `max`
+    pub fn check_min_max<T>(&self, value: T) -> Result<(), TransformError>
+    where
+        T: FromStr + PartialOrd + Display,
+    {
+        if let Some(min) = self.parse::<T>("min")? {
+            if value < min {
+                do yeet TransformError::with_span(
+                    format!("{} is less than the min {}", value, min),
+                    self.get("min").unwrap().span,
+                )
+            }
+        }
+
+        if let Some(max) = self.parse::<T>("max")? {
+            if value > max {
+                do yeet TransformError::with_span(
+                    format!("{} is greater than the max {}", value, max),
+                    self.get("max").unwrap().span,
+                )
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use super::super::errors::deserialize_error::DeserializeError;
+use super::value_types::ValueType;
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeOwned, IntoDeserializer, MapAccess, Visitor};
+use serde::forward_to_deserialize_any;
+use std::collections::{btree_map, BTreeMap};
+
+/// Deserialize a single value into any type implementing [`serde::Deserialize`]
+pub fn from_value<T: DeserializeOwned>(value: ValueType) -> Result<T, DeserializeError> {
+    T::deserialize(ValueDeserializer::new(value))
+}
+
+/// Deserialize the values of an evaluated program into any type implementing
+/// [`serde::Deserialize`]. The keys are the variable names as they are
+/// declared, e.g. use `#[serde(rename_all = "SCREAMING_SNAKE_CASE")]` on the
+/// struct.
+pub fn from_values<T: DeserializeOwned>(
+    values: BTreeMap<String, ValueType>,
+) -> Result<T, DeserializeError> {
+    T::deserialize(ValuesDeserializer::new(values))
+}
+
+pub struct ValueDeserializer {
+    value: ValueType,
+}
+
+impl ValueDeserializer {
+    pub fn new(value: ValueType) -> Self {
+        Self { value }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer {
+    type Error = DeserializeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            ValueType::Null => visitor.visit_unit(),
+            ValueType::Boolean(value) => visitor.visit_bool(value),
+            ValueType::Number(value) => visitor.visit_i64(value),
+            ValueType::UInt(value) => visitor.visit_u64(value),
+            ValueType::Float(value) => visitor.visit_f64(value),
+            ValueType::String(value) => visitor.visit_string(value),
+            ValueType::Custom(_, value) => visitor.visit_string(value),
+            ValueType::List(values) => visitor.visit_seq(SeqDeserializer::new(values.into_iter())),
+            ValueType::Url(url) => visitor.visit_string(url.to_string()),
+            ValueType::Port(port) => visitor.visit_u16(port),
+            ValueType::IpAddr(ip_addr) => visitor.visit_string(ip_addr.to_string()),
+            ValueType::SocketAddr(socket_addr) => visitor.visit_string(socket_addr.to_string()),
+            ValueType::Host(host) => visitor.visit_string(host),
+            ValueType::Map(values) => ValuesDeserializer::new(values).deserialize_any(visitor),
+            ValueType::Secret(secret) => {
+                ValueDeserializer::new(secret.into_inner()).deserialize_any(visitor)
+            }
+            // Same shape used by serde for `std::time::Duration`
+            ValueType::Duration(duration) => visitor.visit_map(MapDeserializer::new(
+                [
+                    ("secs", ValueType::UInt(duration.as_secs())),
+                    ("nanos", ValueType::UInt(u64::from(duration.subsec_nanos()))),
+                ]
+                .into_iter(),
+            )),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            ValueType::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.value {
+            ValueType::String(value) | ValueType::Custom(_, value) => {
+                visitor.visit_enum(value.into_deserializer())
+            }
+            value => ValueDeserializer::new(value).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, DeserializeError> for ValueType {
+    type Deserializer = ValueDeserializer;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        ValueDeserializer::new(self)
+    }
+}
+
+/// Deserializer over the values of an evaluated program. The errors found
+/// deserializing a value keep the name of the variable.
+pub struct ValuesDeserializer {
+    iter: btree_map::IntoIter<String, ValueType>,
+    current: Option<(String, ValueType)>,
+}
+
+impl ValuesDeserializer {
+    pub fn new(values: BTreeMap<String, ValueType>) -> Self {
+        Self {
+            iter: values.into_iter(),
+            current: None,
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ValuesDeserializer {
+    type Error = DeserializeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> MapAccess<'de> for ValuesDeserializer {
+    type Error = DeserializeError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.current = Some((key.clone(), value));
+                seed.deserialize(key.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let (key, value) = match self.current.take() {
+            Some(current) => current,
+            None => do yeet DeserializeError::new("value is missing", None),
+        };
+
+        // The keys of the nested sections are joined, e.g. `DATABASE.HOST`
+        seed.deserialize(ValueDeserializer::new(value))
+            .map_err(|error| {
+                let key = match error.key {
+                    Some(nested_key) => format!("{}.{}", key, nested_key),
+                    None => key,
+                };
+
+                DeserializeError::new(error.message, Some(key))
+            })
+    }
+}
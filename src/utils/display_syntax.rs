@@ -72,6 +72,27 @@ impl DisplaySyntax {
         lines
     }
 
+    /// Replace the characters of the spans by `*`, e.g. to hide the default
+    /// value of a secret variable
+    pub fn redact_payload<T: ToString>(payload: &T, spans: &[Span]) -> String {
+        payload
+            .to_string()
+            .chars()
+            .enumerate()
+            .map(|(index, ch)| {
+                let redacted = spans
+                    .iter()
+                    .any(|span| index >= span.start && index < span.end);
+
+                if redacted && ch != '\n' {
+                    '*'
+                } else {
+                    ch
+                }
+            })
+            .collect()
+    }
+
     pub fn debug_payload<T: ToString>(&self, payload: &T) -> String {
         Self::debug_payload_configurable(&self, payload, &Default::default())
     }
@@ -1 +1,2 @@
+pub mod did_you_mean;
 pub mod display_syntax;
@@ -205,4 +205,62 @@ mod ast_test {
         let tokens = Tokenizer::parse(payload).unwrap();
         assert_debug_snapshot!(AST::parse(tokens).unwrap());
     }
+
+    #[test]
+    fn parse_variable_with_default_value_list() {
+        let payload = r#"HOSTS: List<String> = ["a", "b",
+            "c"]"#;
+        let tokens = Tokenizer::parse(payload).unwrap();
+        assert_debug_snapshot!(AST::parse(tokens).unwrap());
+    }
+
+    #[test]
+    fn parse_include() {
+        let payload = r#"
+            @include "shared/database.envuse"
+            @import "../common.envuse"
+            PORT: Number = 8080
+        "#;
+        let tokens = Tokenizer::parse(payload).unwrap();
+        assert_debug_snapshot!(AST::parse(tokens).unwrap());
+    }
+
+    #[test]
+    fn parse_section() {
+        let payload = r#"
+            database {
+                HOST = "localhost"
+                replica {
+                    HOST: String?
+                }
+            }
+        "#;
+        let tokens = Tokenizer::parse(payload).unwrap();
+        assert_yaml_snapshot!(AST::parse(tokens).unwrap());
+    }
+
+    #[test]
+    fn parse_variable_with_profiles() {
+        let payload = r#"
+            LOG_LEVEL: String = "info" @dev = "debug"
+                @test = "warn"
+            SENTRY_DSN: Url? @prod required
+            @include "shared.envuse"
+        "#;
+        let tokens = Tokenizer::parse(payload).unwrap();
+        assert_yaml_snapshot!(AST::parse(tokens).unwrap());
+    }
+
+    #[test]
+    fn parse_constraints() {
+        let payload = r#"
+            TLS_ENABLED: Boolean = "false"
+            @require TLS_CERT, TLS_KEY when TLS_ENABLED
+            aws {
+                @one_of PROFILE, ACCESS_KEY_ID
+            }
+        "#;
+        let tokens = Tokenizer::parse(payload).unwrap();
+        assert_yaml_snapshot!(AST::parse(tokens).unwrap());
+    }
 }
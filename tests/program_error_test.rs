@@ -7,6 +7,7 @@ mod program_error_test {
         let _err = ProgramError {
             cause: None,
             location: None,
+            files: vec![],
             message: "".to_string(),
             source: "".to_string(),
             span: None,
@@ -95,6 +95,13 @@ mod tokenizer {
         dbg!(tokens);
     }
 
+    #[test]
+    fn tokenizer_number_negative() {
+        let payload = r#"-12_000"#;
+        let tokens = Tokenizer::parse(payload).unwrap();
+        assert_debug_snapshot!(tokens);
+    }
+
     #[test]
     fn tokenizer_number_3() {
         Tokenizer::parse("123").unwrap();